@@ -32,7 +32,9 @@ TEST_HEADER = '''
 use tokenize::Tokenizer;
 
 fn tokenize_assert(test_str: &str, comparison: Vec<&str>) {
-    let tokens: Vec<String> = Tokenizer::new(test_str).collect();
+    let tokens: Vec<String> = Tokenizer::new(test_str)
+        .map(|token| token.value().into_owned())
+        .collect();
     assert_eq!(tokens, comparison, "Tokenizing mismatch for `{}`", test_str);
 }\n'''
 
@@ -0,0 +1,657 @@
+//! Proleptic Gregorian calendar arithmetic based on Julian Day Numbers, and
+//! conversion from the Julian calendar it replaced and from the arithmetic
+//! calendars implementing `Calendar`.
+//! Conversions use floor division throughout, so they're valid well before
+//! the calendar's 1582 introduction and for years before 1 AD, which are
+//! numbered astronomically: 1 BC is year 0. Every function covers at least
+//! the range of chrono's `NaiveDate`, and returns `None` rather than
+//! panicking for dates that don't exist.
+
+use chrono::Datelike;
+use chrono::NaiveDate;
+use chrono::Weekday;
+use std::convert::TryFrom;
+
+use epoch::decimal_nanos;
+
+const DAY_NANOS: i128 = 86_400 * 1_000_000_000;
+
+/// Julian Day Number of 1970-01-01
+const UNIX_JDN: i64 = 2_440_588;
+
+/// Which Julian-Day-style count a number is expressed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JulianDayKind {
+    /// Julian Day: days since noon on -4713-11-24 (proleptic Gregorian)
+    Jd,
+    /// Modified Julian Day: JD - 2400000.5, counting from midnight on 1858-11-17
+    Mjd,
+    /// Reduced Julian Day: JD - 2400000, counting from noon on 1858-11-16
+    Rjd,
+}
+
+impl JulianDayKind {
+    pub(crate) fn from_index(idx: usize) -> Option<Self> {
+        match idx {
+            0 => Some(JulianDayKind::Jd),
+            1 => Some(JulianDayKind::Mjd),
+            2 => Some(JulianDayKind::Rjd),
+            _ => None,
+        }
+    }
+
+    /// Nanoseconds between midnight at the start of JDN 0 and day zero of this count
+    fn offset_nanos(self) -> i128 {
+        match self {
+            JulianDayKind::Jd => DAY_NANOS / 2,
+            JulianDayKind::Mjd => 2_400_001 * DAY_NANOS,
+            JulianDayKind::Rjd => 2_400_000 * DAY_NANOS + DAY_NANOS / 2,
+        }
+    }
+}
+
+/// Convert a decimal Julian Day value, split into sign, integer and fraction
+/// digits, to a proleptic Gregorian date and nanoseconds since midnight.
+pub(crate) fn from_julian_day(
+    kind: JulianDayKind,
+    negative: bool,
+    int: &str,
+    frac: &str,
+) -> Option<((i64, u32, u32), i64)> {
+    let nanos = decimal_nanos(negative, int, frac, DAY_NANOS)?.checked_add(kind.offset_nanos())?;
+    let jdn = nanos.div_euclid(DAY_NANOS);
+    if jdn.abs() > i128::from(i32::MAX) {
+        return None;
+    }
+
+    Some((gregorian_from_jdn(jdn as i64), nanos.rem_euclid(DAY_NANOS) as i64))
+}
+
+/// Whether `year` has a February 29th: years divisible by 4, except
+/// centuries not divisible by 400, so 2000 is a leap year and 1900 isn't
+pub fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+}
+
+/// Number of days in `month` of `year`, or `None` if `month` isn't from 1
+/// to 12
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    month_length(is_leap_year(year), month)
+}
+
+/// Whether `year` has a February 29th in the Julian calendar, where every
+/// fourth year does
+pub fn is_julian_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0
+}
+
+/// Number of days in `month` of `year` in the Julian calendar, or `None` if
+/// `month` isn't from 1 to 12
+pub fn julian_days_in_month(year: i32, month: u32) -> Option<u32> {
+    month_length(is_julian_leap_year(year), month)
+}
+
+/// Proleptic Gregorian date of a Julian calendar date, so 1582-10-05, the
+/// day after the last one Rome kept in the Julian calendar, is 1582-10-15
+pub fn julian_to_gregorian(year: i32, month: u32, day: u32) -> Option<(i32, u32, u32)> {
+    if day < 1 || day > julian_days_in_month(year, month)? {
+        return None;
+    }
+
+    let (year, month, day) = gregorian_from_jdn(jdn_from_julian(i64::from(year), month, day));
+    Some((i32::try_from(year).ok()?, month, day))
+}
+
+fn month_length(leap: bool, month: u32) -> Option<u32> {
+    match month {
+        2 if leap => Some(29),
+        2 => Some(28),
+        4 | 6 | 9 | 11 => Some(30),
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        _ => None,
+    }
+}
+
+/// Day of the year, from 1 for January 1st to 365 or 366
+pub fn day_of_year(year: i32, month: u32, day: u32) -> Option<u32> {
+    check_date(year, month, day)?;
+    Some((1..month).filter_map(|m| days_in_month(year, m)).sum::<u32>() + day)
+}
+
+/// Day of the week
+pub fn weekday(year: i32, month: u32, day: u32) -> Option<Weekday> {
+    check_date(year, month, day)?;
+    // JDN 0 was a Monday
+    let days_from_monday = jdn_from_gregorian(i64::from(year), month, day).rem_euclid(7);
+    Some(match days_from_monday {
+        0 => Weekday::Mon,
+        1 => Weekday::Tue,
+        2 => Weekday::Wed,
+        3 => Weekday::Thu,
+        4 => Weekday::Fri,
+        5 => Weekday::Sat,
+        _ => Weekday::Sun,
+    })
+}
+
+/// ISO 8601 week-numbering year and week, from 1 to 52 or 53. Weeks start on
+/// Monday, and week 1 is the one with the year's first Thursday, so early
+/// January can be in the last week of the year before.
+pub fn iso_week(year: i32, month: u32, day: u32) -> Option<(i32, u32)> {
+    let iso_weekday = weekday(year, month, day)?.number_from_monday();
+    let week = (day_of_year(year, month, day)? + 10 - iso_weekday) / 7;
+
+    if week == 0 {
+        let previous = year.checked_sub(1)?;
+        Some((previous, iso_weeks_in_year(previous)?))
+    } else if week > iso_weeks_in_year(year)? {
+        Some((year.checked_add(1)?, 1))
+    } else {
+        Some((year, week))
+    }
+}
+
+/// 53 for years starting on a Thursday, and leap years starting on a
+/// Wednesday; 52 otherwise
+fn iso_weeks_in_year(year: i32) -> Option<u32> {
+    match weekday(year, 1, 1)? {
+        Weekday::Thu => Some(53),
+        Weekday::Wed if is_leap_year(year) => Some(53),
+        _ => Some(52),
+    }
+}
+
+fn check_date(year: i32, month: u32, day: u32) -> Option<()> {
+    if day >= 1 && day <= days_in_month(year, month)? {
+        Some(())
+    } else {
+        None
+    }
+}
+
+/// Julian Day Number of a proleptic Gregorian date
+pub(crate) fn jdn_from_gregorian(year: i64, month: u32, day: u32) -> i64 {
+    // From http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = (i64::from(month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468 + UNIX_JDN
+}
+
+/// Julian Day Number of a Julian calendar date
+fn jdn_from_julian(year: i64, month: u32, day: u32) -> i64 {
+    // Counting from March, as `jdn_from_gregorian` does, puts leap days at
+    // the end of the year
+    let year = if month <= 2 { year - 1 } else { year };
+    let mp = (i64::from(month) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+
+    year * 365 + year.div_euclid(4) + doy + 1_721_118
+}
+
+/// Proleptic Gregorian date of a Julian Day Number
+pub(crate) fn gregorian_from_jdn(jdn: i64) -> (i64, u32, u32) {
+    // From http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = jdn - UNIX_JDN + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month as u32, day as u32)
+}
+
+/// A calendar other than the Gregorian one that `Parser::with_calendar` can
+/// read dates in. Years, months and days are numbered as the calendar
+/// numbers them, starting from 1.
+pub trait Calendar: Send + Sync {
+    /// Names of each month in order, with every spelling that should be
+    /// recognized; like `ParserInfo::months`, each name may be several
+    /// words, such as "Rabi' al-awwal"
+    fn month_names(&self) -> Vec<Vec<&'static str>>;
+
+    /// Number of days in `month` of `year`, or `None` if the year has no
+    /// such month
+    fn days_in_month(&self, year: i32, month: u32) -> Option<u32>;
+
+    /// Proleptic Gregorian date of `day` of `month` in `year`, or `None` if
+    /// there's no such date or chrono can't represent it
+    fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate>;
+
+    /// Year, month and day of a proleptic Gregorian date in this calendar
+    fn ymd(&self, date: NaiveDate) -> (i32, u32, u32);
+}
+
+/// Julian Day Number of 1 Farvardin 1 in the Solar Hijri calendar, a day
+/// before the historical epoch so the 33-year cycle matches the calendar
+/// Iran observes
+const PERSIAN_EPOCH: i64 = 1_948_320;
+
+/// The Solar Hijri (Jalali) calendar of Iran and Afghanistan, using the
+/// 33-year cycle of leap years, which agrees with the astronomical calendar
+/// from 1178 to 1634 AP (1799 to 2256 AD). The first six months have 31
+/// days, the next five 30, and Esfand 29 or 30.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Persian;
+
+impl Persian {
+    fn is_leap_year(year: i64) -> bool {
+        (25 * year + 11).rem_euclid(33) < 8
+    }
+
+    fn new_year(year: i64) -> i64 {
+        PERSIAN_EPOCH + 365 * (year - 1) + (8 * year + 21).div_euclid(33)
+    }
+}
+
+impl Calendar for Persian {
+    fn month_names(&self) -> Vec<Vec<&'static str>> {
+        vec![
+            vec!["Farvardin", "فروردین"],
+            vec!["Ordibehesht", "اردیبهشت"],
+            vec!["Khordad", "خرداد"],
+            vec!["Tir", "تیر"],
+            vec!["Mordad", "Amordad", "مرداد"],
+            vec!["Shahrivar", "شهریور"],
+            vec!["Mehr", "مهر"],
+            vec!["Aban", "آبان"],
+            vec!["Azar", "آذر"],
+            vec!["Dey", "دی"],
+            vec!["Bahman", "بهمن"],
+            vec!["Esfand", "اسفند"],
+        ]
+    }
+
+    fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+        match month {
+            1..=6 => Some(31),
+            7..=11 => Some(30),
+            12 if Persian::is_leap_year(i64::from(year)) => Some(30),
+            12 => Some(29),
+            _ => None,
+        }
+    }
+
+    fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        check_day(self, year, month, day)?;
+        let month = i64::from(month);
+        let before = if month <= 7 { 31 * (month - 1) } else { 30 * (month - 1) + 6 };
+        naive_from_jdn(Persian::new_year(i64::from(year)) + before + i64::from(day) - 1)
+    }
+
+    fn ymd(&self, date: NaiveDate) -> (i32, u32, u32) {
+        let jdn = jdn_from_naive(date);
+        // 12053 days in 33 years
+        let mut year = ((jdn - PERSIAN_EPOCH) * 33).div_euclid(12_053) + 1;
+        while Persian::new_year(year + 1) <= jdn {
+            year += 1;
+        }
+        while Persian::new_year(year) > jdn {
+            year -= 1;
+        }
+
+        let doy = jdn - Persian::new_year(year);
+        let (month, day) = if doy < 186 {
+            (doy / 31 + 1, doy % 31 + 1)
+        } else {
+            ((doy - 186) / 30 + 7, (doy - 186) % 30 + 1)
+        };
+        (year as i32, month as u32, day as u32)
+    }
+}
+
+/// Julian Day Number of 1 Muharram 1 AH, July 16th 622 in the Julian
+/// calendar
+const ISLAMIC_EPOCH: i64 = 1_948_440;
+
+/// The tabular Islamic calendar, an arithmetic approximation of the lunar
+/// Hijri calendar with 11 leap years in every 30, using the most common
+/// leap years (2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29) and the civil
+/// epoch. Dates can differ by a day or two from those set by sighting the
+/// moon or from the Umm al-Qura calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TabularIslamic;
+
+impl TabularIslamic {
+    fn is_leap_year(year: i64) -> bool {
+        (14 + 11 * year).rem_euclid(30) < 11
+    }
+
+    fn jdn(year: i64, month: i64, day: i64) -> i64 {
+        ISLAMIC_EPOCH - 1 + 354 * (year - 1) + (3 + 11 * year).div_euclid(30)
+            + 29 * (month - 1)
+            + month / 2
+            + day
+    }
+}
+
+impl Calendar for TabularIslamic {
+    fn month_names(&self) -> Vec<Vec<&'static str>> {
+        vec![
+            vec!["Muharram", "محرم"],
+            vec!["Safar", "صفر"],
+            vec!["Rabi' al-awwal", "Rabi al-Awwal", "Rabi I", "ربيع الأول"],
+            vec!["Rabi' al-thani", "Rabi al-Thani", "Rabi' al-akhir", "Rabi II", "ربيع الآخر"],
+            vec!["Jumada al-awwal", "Jumada al-Ula", "Jumada I", "جمادى الأولى"],
+            vec!["Jumada al-thani", "Jumada al-Akhirah", "Jumada II", "جمادى الآخرة"],
+            vec!["Rajab", "رجب"],
+            vec!["Sha'ban", "Shaban", "شعبان"],
+            vec!["Ramadan", "Ramadhan", "رمضان"],
+            vec!["Shawwal", "شوال"],
+            vec!["Dhu al-Qi'dah", "Dhu al-Qadah", "Dhu'l-Qi'dah", "ذو القعدة"],
+            vec!["Dhu al-Hijjah", "Dhu'l-Hijjah", "ذو الحجة"],
+        ]
+    }
+
+    fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+        match month {
+            12 if TabularIslamic::is_leap_year(i64::from(year)) => Some(30),
+            1..=12 if month % 2 == 1 => Some(30),
+            1..=12 => Some(29),
+            _ => None,
+        }
+    }
+
+    fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        check_day(self, year, month, day)?;
+        naive_from_jdn(TabularIslamic::jdn(i64::from(year), i64::from(month), i64::from(day)))
+    }
+
+    fn ymd(&self, date: NaiveDate) -> (i32, u32, u32) {
+        let jdn = jdn_from_naive(date);
+        // 10631 days in 30 years
+        let mut year = ((jdn - ISLAMIC_EPOCH) * 30).div_euclid(10_631) + 1;
+        while TabularIslamic::jdn(year + 1, 1, 1) <= jdn {
+            year += 1;
+        }
+        while TabularIslamic::jdn(year, 1, 1) > jdn {
+            year -= 1;
+        }
+
+        let month = (1..12)
+            .find(|&month| jdn < TabularIslamic::jdn(year, month + 1, 1))
+            .unwrap_or(12);
+        let day = jdn - TabularIslamic::jdn(year, month, 1) + 1;
+        (year as i32, month as u32, day as u32)
+    }
+}
+
+/// Julian Day Number of 1 Tishri 1 AM, October 7th 3761 BC in the Julian
+/// calendar
+const HEBREW_EPOCH: i64 = 347_998;
+
+/// The Hebrew calendar, with leap years adding Adar II in 7 of every 19
+/// years. Months are numbered from Nisan as in the Bible, so Tishri, which
+/// starts the year, is month 7; Adar is month 12, which is Adar I in leap
+/// years, and Adar II is month 13.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Hebrew;
+
+impl Hebrew {
+    fn is_leap_year(year: i64) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    fn last_month(year: i64) -> i64 {
+        if Hebrew::is_leap_year(year) {
+            13
+        } else {
+            12
+        }
+    }
+
+    /// Days from the epoch to the molad of Tishri, moved to avoid Sunday,
+    /// Wednesday and Friday
+    fn elapsed_days(year: i64) -> i64 {
+        let months = (235 * year - 234).div_euclid(19);
+        let parts = 12_084 + 13_753 * months;
+        let day = 29 * months + parts.div_euclid(25_920);
+        if (3 * (day + 1)).rem_euclid(7) < 3 {
+            day + 1
+        } else {
+            day
+        }
+    }
+
+    /// Julian Day Number of 1 Tishri, delayed further to keep every year
+    /// 353 to 355 or 383 to 385 days long
+    fn new_year(year: i64) -> i64 {
+        let (previous, this, next) = (
+            Hebrew::elapsed_days(year - 1),
+            Hebrew::elapsed_days(year),
+            Hebrew::elapsed_days(year + 1),
+        );
+        let delay = if next - this == 356 {
+            2
+        } else if this - previous == 382 {
+            1
+        } else {
+            0
+        };
+        HEBREW_EPOCH + this + delay
+    }
+
+    fn month_length(year: i64, month: i64) -> i64 {
+        let year_length = Hebrew::new_year(year + 1) - Hebrew::new_year(year);
+        let short = match month {
+            2 | 4 | 6 | 10 | 13 => true,
+            12 => !Hebrew::is_leap_year(year),
+            // Heshvan is long and Kislev short in some years
+            8 => year_length % 10 != 5,
+            9 => year_length % 10 == 3,
+            _ => false,
+        };
+        if short {
+            29
+        } else {
+            30
+        }
+    }
+
+    /// Months of `year` in the order they fall, from Tishri
+    fn months(year: i64) -> impl Iterator<Item = i64> {
+        (7..=Hebrew::last_month(year)).chain(1..7)
+    }
+
+    fn jdn(year: i64, month: i64, day: i64) -> i64 {
+        let before: i64 = Hebrew::months(year)
+            .take_while(|&m| m != month)
+            .map(|m| Hebrew::month_length(year, m))
+            .sum();
+        Hebrew::new_year(year) + before + day - 1
+    }
+}
+
+impl Calendar for Hebrew {
+    fn month_names(&self) -> Vec<Vec<&'static str>> {
+        vec![
+            vec!["Nisan", "ניסן"],
+            vec!["Iyyar", "Iyar", "אייר"],
+            vec!["Sivan", "סיון", "סיוון"],
+            vec!["Tammuz", "Tamuz", "תמוז"],
+            vec!["Av", "אב"],
+            vec!["Elul", "אלול"],
+            vec!["Tishri", "Tishrei", "תשרי"],
+            vec!["Heshvan", "Cheshvan", "Marcheshvan", "חשון", "חשוון"],
+            vec!["Kislev", "כסלו"],
+            vec!["Tevet", "Teves", "טבת"],
+            vec!["Shevat", "Shvat", "שבט"],
+            vec!["Adar", "Adar I", "אדר", "אדר א׳"],
+            vec!["Adar II", "אדר ב׳"],
+        ]
+    }
+
+    fn days_in_month(&self, year: i32, month: u32) -> Option<u32> {
+        let year = i64::from(year);
+        if month < 1 || i64::from(month) > Hebrew::last_month(year) {
+            return None;
+        }
+        Some(Hebrew::month_length(year, i64::from(month)) as u32)
+    }
+
+    fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        check_day(self, year, month, day)?;
+        naive_from_jdn(Hebrew::jdn(i64::from(year), i64::from(month), i64::from(day)))
+    }
+
+    fn ymd(&self, date: NaiveDate) -> (i32, u32, u32) {
+        let jdn = jdn_from_naive(date);
+        // 35975351 days in 98496 years
+        let mut year = ((jdn - HEBREW_EPOCH) * 98_496).div_euclid(35_975_351) + 1;
+        while Hebrew::new_year(year + 1) <= jdn {
+            year += 1;
+        }
+        while Hebrew::new_year(year) > jdn {
+            year -= 1;
+        }
+
+        let mut start = Hebrew::new_year(year);
+        for month in Hebrew::months(year) {
+            let length = Hebrew::month_length(year, month);
+            if jdn < start + length {
+                return (year as i32, month as u32, (jdn - start + 1) as u32);
+            }
+            start += length;
+        }
+        unreachable!("Hebrew year {} doesn't contain JDN {}", year, jdn)
+    }
+}
+
+fn check_day<C: Calendar + ?Sized>(calendar: &C, year: i32, month: u32, day: u32) -> Option<()> {
+    if day >= 1 && day <= calendar.days_in_month(year, month)? {
+        Some(())
+    } else {
+        None
+    }
+}
+
+fn jdn_from_naive(date: NaiveDate) -> i64 {
+    jdn_from_gregorian(i64::from(date.year()), date.month(), date.day())
+}
+
+fn naive_from_jdn(jdn: i64) -> Option<NaiveDate> {
+    let (year, month, day) = gregorian_from_jdn(jdn);
+    NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use calendar::*;
+    use chrono::Weekday;
+
+    #[test]
+    fn jdn_to_gregorian() {
+        let cases = [
+            (0, (-4713, 11, 24)),
+            (1_721_426, (1, 1, 1)),
+            (1_721_425, (0, 12, 31)),
+            (2_299_161, (1582, 10, 15)),
+            (2_415_079, (1900, 2, 28)),
+            (2_415_080, (1900, 3, 1)),
+            (2_451_604, (2000, 2, 29)),
+            (2_440_588, (1970, 1, 1)),
+        ];
+
+        for &(jdn, ymd) in cases.iter() {
+            assert_eq!(gregorian_from_jdn(jdn), ymd);
+            assert_eq!(jdn_from_gregorian(ymd.0, ymd.1, ymd.2), jdn);
+        }
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(is_leap_year(-400));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2023));
+        assert!(!is_leap_year(-1));
+        assert!(!is_leap_year(-100));
+
+        assert_eq!(days_in_month(1900, 2), Some(28));
+        assert_eq!(days_in_month(2000, 2), Some(29));
+        assert_eq!(days_in_month(2023, 4), Some(30));
+        assert_eq!(days_in_month(2023, 0), None);
+        assert_eq!(days_in_month(2023, 13), None);
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(weekday(2018, 6, 24), Some(Weekday::Sun));
+        assert_eq!(weekday(2003, 9, 25), Some(Weekday::Thu));
+        assert_eq!(weekday(0, 1, 1), Some(Weekday::Sat));
+        assert_eq!(weekday(-1, 12, 31), Some(Weekday::Fri));
+        assert_eq!(weekday(-4713, 11, 24), Some(Weekday::Mon));
+        assert_eq!(weekday(2023, 2, 29), None);
+        assert_eq!(weekday(2023, 13, 1), None);
+    }
+
+    #[test]
+    fn days_of_year() {
+        assert_eq!(day_of_year(2023, 1, 1), Some(1));
+        assert_eq!(day_of_year(2023, 12, 31), Some(365));
+        assert_eq!(day_of_year(2024, 12, 31), Some(366));
+        assert_eq!(day_of_year(1900, 3, 1), Some(60));
+        assert_eq!(day_of_year(2000, 3, 1), Some(61));
+        assert_eq!(day_of_year(2024, 4, 31), None);
+    }
+
+    #[test]
+    fn iso_weeks() {
+        assert_eq!(iso_week(2024, 1, 1), Some((2024, 1)));
+        assert_eq!(iso_week(2021, 1, 3), Some((2020, 53)));
+        assert_eq!(iso_week(2019, 12, 30), Some((2020, 1)));
+        assert_eq!(iso_week(2026, 12, 31), Some((2026, 53)));
+        assert_eq!(iso_week(2027, 1, 1), Some((2026, 53)));
+        // Years at the edges of `i32` don't overflow
+        let _ = iso_week(i32::MAX, 12, 31);
+        let _ = iso_week(i32::MIN, 1, 1);
+    }
+
+    #[test]
+    fn julian_calendar_dates() {
+        assert_eq!(jdn_from_julian(-4712, 1, 1), 0);
+        assert_eq!(julian_to_gregorian(1582, 10, 4), Some((1582, 10, 14)));
+        assert_eq!(julian_to_gregorian(1752, 9, 2), Some((1752, 9, 13)));
+        assert_eq!(julian_to_gregorian(1700, 2, 29), Some((1700, 3, 11)));
+        assert_eq!(julian_to_gregorian(1918, 1, 31), Some((1918, 2, 13)));
+        assert_eq!(julian_to_gregorian(0, 12, 30), Some((0, 12, 28)));
+        assert_eq!(julian_to_gregorian(200, 3, 1), Some((200, 3, 1)));
+        assert_eq!(julian_to_gregorian(1701, 2, 29), None);
+        assert_eq!(julian_to_gregorian(1700, 13, 1), None);
+        assert_eq!(julian_days_in_month(1900, 2), Some(29));
+        assert!(is_julian_leap_year(-4) && !is_julian_leap_year(-1));
+        let _ = julian_to_gregorian(i32::MAX, 12, 31);
+        let _ = julian_to_gregorian(i32::MIN, 1, 1);
+    }
+
+    #[test]
+    fn julian_day_fractions() {
+        assert_eq!(
+            from_julian_day(JulianDayKind::Jd, false, "2451545", "0"),
+            Some(((2000, 1, 1), 12 * 3_600_000_000_000))
+        );
+        assert_eq!(
+            from_julian_day(JulianDayKind::Mjd, false, "51544", "25"),
+            Some(((2000, 1, 1), 6 * 3_600_000_000_000))
+        );
+        assert_eq!(
+            from_julian_day(JulianDayKind::Rjd, false, "51544", ""),
+            Some(((1999, 12, 31), 12 * 3_600_000_000_000))
+        );
+        assert_eq!(
+            from_julian_day(JulianDayKind::Jd, true, "0", "5"),
+            Some(((-4713, 11, 24), 0))
+        );
+    }
+}
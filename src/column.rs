@@ -0,0 +1,507 @@
+//! Bulk parsing for columns of timestamps that all share one layout, such as
+//! a field in a CSV export.
+//!
+//! `ColumnParser` parses the first few values with the full `Parser` and asks
+//! it for the strftime pattern describing each one (see `Parser::infer_format`).
+//! If they all agree, the pattern is compiled into a layout that later values
+//! are matched against directly, skipping tokenization and the general parsing
+//! heuristics. Values that don't fit the layout are handed to the full parser,
+//! so results are always the same as parsing each value individually.
+//!
+//! ```rust,ignore (tests-dont-compile-on-old-rust)
+//! # extern crate dtparse;
+//! use dtparse::column::ColumnParser;
+//! use dtparse::Parser;
+//!
+//! let mut column = ColumnParser::new(Parser::default(), 3);
+//! for value in &["2024-03-05 14:30", "2024-03-06 09:15", "2024-03-07 18:45", "2024-03-08 07:00"] {
+//!     column.parse(value).unwrap();
+//! }
+//! assert_eq!(column.layout(), Some("%Y-%m-%d %H:%M"));
+//! assert_eq!(column.fallbacks(), 0);
+//! ```
+//!
+//! A column should also use one date order throughout. `parse_column` finds
+//! the order its all-numeric dates agree on before parsing any of them, so
+//! "03/04/2024" is read the same way as the "25/04/2024" further down.
+
+use chrono::FixedOffset;
+use chrono::Local;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use DateOrder;
+use Limits;
+use ParseError;
+use ParseResult;
+use Parser;
+use ParsingResult;
+use split_timestamp;
+use YMDLabel;
+use YMD;
+
+/// Which timestamp field a numeric value in a layout fills
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Item {
+    Literal(String),
+    /// Digits, with the minimum and maximum number allowed
+    Number(Field, usize, usize),
+    MonthName,
+    Weekday,
+    AmPm,
+    /// Fractional seconds with an exact number of digits
+    Fraction(usize),
+    /// Timezone offset, with or without a colon
+    Offset(bool),
+}
+
+/// A strftime pattern compiled for matching
+#[derive(Debug)]
+struct Layout {
+    pattern: String,
+    items: Vec<Item>,
+    /// Offset the samples derived from literal text like "UTC", if the
+    /// pattern has no `%z`
+    offset: Option<FixedOffset>,
+}
+
+impl Layout {
+    fn compile(pattern: &str, offset: Option<FixedOffset>) -> Option<Layout> {
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+
+            let mut spec = chars.next()?;
+            let mut flag = None;
+            if spec == '-' || spec == ':' || spec.is_ascii_digit() {
+                flag = Some(spec);
+                spec = chars.next()?;
+            }
+            let min_len = if flag == Some('-') { 1 } else { 2 };
+
+            let item = match spec {
+                '%' => {
+                    literal.push('%');
+                    continue;
+                }
+                'Y' => Item::Number(Field::Year, 4, 4),
+                'y' => Item::Number(Field::Year, 2, 2),
+                'm' => Item::Number(Field::Month, min_len, 2),
+                'd' => Item::Number(Field::Day, min_len, 2),
+                'H' | 'I' => Item::Number(Field::Hour, min_len, 2),
+                'M' => Item::Number(Field::Minute, min_len, 2),
+                'S' => Item::Number(Field::Second, min_len, 2),
+                'b' | 'B' => Item::MonthName,
+                'a' | 'A' => Item::Weekday,
+                'p' | 'P' => Item::AmPm,
+                'f' => Item::Fraction(flag?.to_digit(10)? as usize),
+                'z' => Item::Offset(flag == Some(':')),
+                _ => return None,
+            };
+
+            if !literal.is_empty() {
+                items.push(Item::Literal(literal.clone()));
+                literal.clear();
+            }
+            items.push(item);
+        }
+
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+
+        let offset = if items.iter().any(|item| matches!(item, Item::Offset(_))) {
+            None
+        } else {
+            offset
+        };
+
+        Some(Layout {
+            pattern: pattern.to_owned(),
+            items,
+            offset,
+        })
+    }
+}
+
+/// Result of parsing a single value in a column
+type Parsed = ParseResult<(NaiveDateTime, Option<FixedOffset>)>;
+
+/// Find the date order that every all-numeric date in `values` can be read
+/// in. Dates with a number over 12, or a four-digit year, rule out orders
+/// that would make them invalid; values with a month name, or that don't
+/// parse at all, don't affect the result. If several orders remain, the one
+/// set in the parser's `ParserInfo` is preferred, then the order of
+/// `DateOrder::ALL`.
+///
+/// Returns `ParseError::InconsistentDateOrder` with the first value that
+/// rules out every order still possible for the values before it.
+pub fn infer_date_order(parser: &Parser, values: &[&str]) -> ParseResult<DateOrder> {
+    let preferred = DateOrder::from_flags(parser.info.dayfirst, parser.info.yearfirst);
+    let mut candidates = DateOrder::ranked(preferred);
+
+    for value in values {
+        let ymd = match parser.scan_tokens(value, false) {
+            Ok(scanned) => scanned.ymd,
+            Err(_) => continue,
+        };
+        let fitting: Vec<DateOrder> = candidates
+            .iter()
+            .cloned()
+            .filter(|&order| ymd.fits_order(order, parser.calendar.as_deref()) != Some(false))
+            .collect();
+        if fitting.is_empty() {
+            return Err(ParseError::InconsistentDateOrder(value.to_string()));
+        }
+        candidates = fitting;
+    }
+
+    Ok(candidates[0])
+}
+
+/// Parse every value in `values` with the date order found by
+/// `infer_date_order`, using a `ColumnParser` that learns its layout from
+/// the first `samples` values. Fails as a whole only if the values disagree
+/// on the date order; other errors are reported for each value.
+pub fn parse_column(parser: Parser, samples: usize, values: &[&str]) -> ParseResult<(DateOrder, Vec<Parsed>)> {
+    let order = infer_date_order(&parser, values)?;
+    let mut column = ColumnParser::new(parser.with_date_order(order), samples);
+    let parsed = values.iter().map(|value| column.parse(value)).collect();
+    Ok((order, parsed))
+}
+
+/// Values pulled out of a string by a `Layout`
+#[derive(Default)]
+struct Matched<'a> {
+    /// Year, month and day values in the order they appear, with their text
+    /// and whether they were a month name
+    ymd: Vec<(Field, i32, &'a str, bool)>,
+    weekday: Option<usize>,
+    hour: Option<i32>,
+    minute: Option<i32>,
+    second: Option<i32>,
+    nanosecond: Option<i64>,
+    ampm: Option<bool>,
+    offset: Option<i32>,
+}
+
+/// Parses a column of timestamps, learning the column's layout from its
+/// first values and using it to parse the rest quickly.
+pub struct ColumnParser {
+    parser: Parser,
+    samples: usize,
+    default: NaiveDateTime,
+    patterns: Vec<Option<String>>,
+    layout: Option<Layout>,
+    fast: usize,
+    fallbacks: usize,
+}
+
+impl ColumnParser {
+    /// Create a `ColumnParser` that learns its layout from the first `samples`
+    /// values. A layout is only used if all of those values are described by
+    /// the same pattern. Missing fields are filled from midnight of the
+    /// current day, as with `Parser::parse`.
+    pub fn new(parser: Parser, samples: usize) -> Self {
+        let default = Local::now().naive_local().date().and_hms_opt(0, 0, 0).unwrap();
+        ColumnParser {
+            parser,
+            samples,
+            default,
+            patterns: Vec::new(),
+            layout: None,
+            fast: 0,
+            fallbacks: 0,
+        }
+    }
+
+    /// Parse the next value in the column, returning the same result
+    /// `Parser::parse` would with default arguments.
+    pub fn parse(&mut self, value: &str) -> Parsed {
+        if self.patterns.len() < self.samples {
+            let result = self.parse_general(value);
+            self.learn(value, result.as_ref().ok().and_then(|r| r.1));
+            return result;
+        }
+
+        if let Some(result) = self.layout.as_ref().and_then(|layout| self.parse_layout(layout, value)) {
+            self.fast += 1;
+            return Ok(result);
+        }
+
+        self.fallbacks += 1;
+        self.parse_general(value)
+    }
+
+    /// The pattern learned from the first values, if they agreed on one
+    pub fn layout(&self) -> Option<&str> {
+        self.layout.as_ref().map(|layout| layout.pattern.as_str())
+    }
+
+    /// Number of values parsed using the learned layout
+    pub fn fast_parsed(&self) -> usize {
+        self.fast
+    }
+
+    /// Number of values after the learning phase that needed the full parser,
+    /// either because no layout was learned or because they didn't fit it
+    pub fn fallbacks(&self) -> usize {
+        self.fallbacks
+    }
+
+    fn parse_general(&self, value: &str) -> Parsed {
+        let (naive, offset, _) = self.parser.parse(
+            value,
+            None,
+            None,
+            false,
+            false,
+            Some(&self.default),
+            false,
+            &HashMap::new(),
+        )?;
+        Ok((naive, offset))
+    }
+
+    fn learn(&mut self, value: &str, offset: Option<FixedOffset>) {
+        let pattern = if self.parser.timestamps.is_some() && split_timestamp(value).is_some() {
+            None
+        } else {
+            self.parser.infer_format(value).ok()
+        };
+        self.patterns.push(pattern);
+
+        if self.patterns.len() == self.samples {
+            self.layout = merge_patterns(&self.patterns).and_then(|p| Layout::compile(&p, offset));
+        }
+    }
+
+    /// Parse `value` with the learned layout, or `None` if it doesn't fit or
+    /// the full parser might read it differently
+    fn parse_layout(&self, layout: &Layout, value: &str) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+        // Lone numbers are epoch timestamps to the full parser
+        if self.parser.timestamps.is_some() && split_timestamp(value).is_some() {
+            return None;
+        }
+        // Leave values that break the limits for the full parser to reject
+        if self.parser.limits != Limits::default() && self.parser.tokenize_within_limits(value).is_err() {
+            return None;
+        }
+
+        let vocab = &self.parser.vocab;
+        let mut matched = Matched::default();
+        let mut rest = value;
+
+        for item in &layout.items {
+            match *item {
+                Item::Literal(ref text) => {
+                    rest = rest.strip_prefix(text.as_str())?;
+                }
+                Item::Number(field, min_len, max_len) => {
+                    let len = rest.bytes().take(max_len).take_while(u8::is_ascii_digit).count();
+                    if len < min_len {
+                        return None;
+                    }
+                    let (digits, next) = rest.split_at(len);
+                    let number = digits.parse::<i32>().ok()?;
+                    match field {
+                        Field::Year | Field::Month | Field::Day => matched.ymd.push((field, number, digits, false)),
+                        Field::Hour => matched.hour = Some(number),
+                        Field::Minute => matched.minute = Some(number),
+                        Field::Second => matched.second = Some(number),
+                    }
+                    rest = next;
+                }
+                Item::MonthName => {
+                    let (name, next) = split_alphabetic(rest);
+                    let month = vocab.lookup(name).month?;
+                    matched.ymd.push((Field::Month, month as i32, name, true));
+                    rest = next;
+                }
+                Item::Weekday => {
+                    let (name, next) = split_alphabetic(rest);
+                    matched.weekday = Some(vocab.lookup(name).weekday?);
+                    rest = next;
+                }
+                Item::AmPm => {
+                    let (name, next) = split_alphabetic(rest);
+                    matched.ampm = Some(vocab.lookup(name).ampm?);
+                    rest = next;
+                }
+                Item::Fraction(digits) => {
+                    if rest.len() < digits || !rest.as_bytes()[..digits].iter().all(u8::is_ascii_digit) {
+                        return None;
+                    }
+                    let (frac, next) = rest.split_at(digits);
+                    matched.nanosecond = Some(frac.parse::<i64>().ok()? * 10i64.pow(9 - digits as u32));
+                    rest = next;
+                }
+                Item::Offset(colon) => {
+                    let (sign, next) = match rest.as_bytes().first() {
+                        Some(b'+') => (1, &rest[1..]),
+                        Some(b'-') => (-1, &rest[1..]),
+                        _ => return None,
+                    };
+                    // Checked byte by byte, so `len` is a character boundary
+                    // once they all match
+                    let bytes = next.as_bytes();
+                    let len = if colon { 5 } else { 4 };
+                    if bytes.len() < len || (colon && bytes[2] != b':') {
+                        return None;
+                    }
+                    let (hours, minutes) = (&bytes[..2], &bytes[len - 2..len]);
+                    if !hours.iter().chain(minutes).all(u8::is_ascii_digit) {
+                        return None;
+                    }
+                    let number = |digits: &[u8]| i32::from(digits[0] - b'0') * 10 + i32::from(digits[1] - b'0');
+                    matched.offset = Some(sign * (number(hours) * 3600 + number(minutes) * 60));
+                    rest = &next[len..];
+                }
+            }
+        }
+
+        if !rest.is_empty() {
+            return None;
+        }
+
+        self.build(layout, matched)
+    }
+
+    fn build(&self, layout: &Layout, matched: Matched) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+        let info = &self.parser.info;
+
+        // Replay the year/month/day values through the same resolution the full
+        // parser uses, and only continue if it agrees with the layout.
+        let mut ymd = YMD::default();
+        for &(_, number, text, is_name) in &matched.ymd {
+            let label = if is_name { Some(YMDLabel::Month) } else { None };
+            ymd.append(number, text, label).ok()?;
+        }
+        let (y, m, d) = self
+            .parser
+            .resolve_ymd(&mut ymd, info.yearfirst, info.dayfirst, self.parser.explicit_order)
+            .ok()?;
+        for (idx, &(field, _, _, _)) in matched.ymd.iter().enumerate() {
+            let resolved = if y == Some(idx) {
+                Field::Year
+            } else if m == Some(idx) {
+                Field::Month
+            } else if d == Some(idx) {
+                Field::Day
+            } else {
+                return None;
+            };
+            if resolved != field {
+                return None;
+            }
+        }
+
+        let mut res = ParsingResult {
+            year: y.map(|i| ymd._ymd[i]),
+            month: m.map(|i| ymd._ymd[i]),
+            day: d.map(|i| ymd._ymd[i]),
+            weekday: matched.weekday,
+            hour: matched.hour,
+            minute: matched.minute,
+            second: matched.second,
+            nanosecond: matched.nanosecond,
+            tzoffset: matched.offset.or_else(|| layout.offset.map(|offset| offset.local_minus_utc())),
+            ampm: matched.ampm,
+            century_specified: ymd.century_specified,
+            ..ParsingResult::default()
+        };
+        if let (Some(hour), Some(ampm)) = (res.hour, res.ampm) {
+            res.hour = Some(self.parser.adjust_ampm(hour, ampm));
+        }
+
+        // From here on, values take the same path as in `Parser::parse`
+        self.parser.adjust_year(&mut res).ok()?;
+        if !info.validate(&mut res, &self.parser.vocab) {
+            return None;
+        }
+        self.parser.build_result(&res, &self.default, false, &HashMap::new()).ok()
+    }
+}
+
+/// Split a pattern into literal characters and specifiers, with the `-`
+/// flag removed from specifiers and noted separately
+fn pattern_parts(pattern: &str) -> Vec<(String, bool)> {
+    let mut parts = Vec::new();
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            parts.push((c.to_string(), false));
+            continue;
+        }
+
+        let mut spec = String::from("%");
+        let mut unpadded = false;
+        for next in chars.by_ref() {
+            if next == '-' {
+                unpadded = true;
+            } else {
+                spec.push(next);
+                if !(next == ':' || next.is_ascii_digit()) {
+                    break;
+                }
+            }
+        }
+        parts.push((spec, unpadded));
+    }
+
+    parts
+}
+
+/// Combine the samples' patterns into one, if they all describe the same
+/// layout. "12" is described by both `%I` and `%-I`, so patterns differing
+/// only in padding are compatible, and the result is unpadded wherever any
+/// of them is.
+fn merge_patterns(patterns: &[Option<String>]) -> Option<String> {
+    let mut merged = pattern_parts(patterns.first()?.as_ref()?);
+
+    for pattern in &patterns[1..] {
+        let parts = pattern_parts(pattern.as_ref()?);
+        if parts.len() != merged.len() {
+            return None;
+        }
+        for (part, merged) in parts.into_iter().zip(merged.iter_mut()) {
+            if part.0 != merged.0 {
+                return None;
+            }
+            merged.1 |= part.1;
+        }
+    }
+
+    Some(
+        merged
+            .into_iter()
+            .map(|(part, unpadded)| if unpadded { part.replacen('%', "%-", 1) } else { part })
+            .collect(),
+    )
+}
+
+fn split_alphabetic(s: &str) -> (&str, &str) {
+    let len = s
+        .char_indices()
+        .find(|&(_, c)| !c.is_alphabetic())
+        .map_or(s.len(), |(idx, _)| idx);
+    s.split_at(len)
+}
+
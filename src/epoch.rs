@@ -0,0 +1,295 @@
+//! Conversions for numeric timestamps counted from epochs other than
+//! 1970-01-01, such as those found in Windows, Apple and network software
+//! or spreadsheet exports.
+//!
+//! ```rust,ignore (tests-dont-compile-on-old-rust)
+//! # extern crate chrono;
+//! # extern crate dtparse;
+//! use chrono::NaiveDate;
+//! use dtparse::epoch::Epoch;
+//!
+//! assert_eq!(
+//!     Epoch::FileTime.to_naive(132_223_104_000_000_000),
+//!     Some(NaiveDate::from_ymd(2020, 1, 1).and_hms(0, 0, 0))
+//! );
+//! ```
+
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+
+/// A reference instant and tick size used to encode timestamps as numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Epoch {
+    /// Seconds since 1970-01-01T00:00:00
+    Unix,
+    /// Windows `FILETIME`: 100-nanosecond ticks since 1601-01-01T00:00:00
+    FileTime,
+    /// .NET `DateTime.Ticks`: 100-nanosecond ticks since 0001-01-01T00:00:00
+    DotNetTicks,
+    /// Cocoa `NSDate`/`CFAbsoluteTime`: seconds since 2001-01-01T00:00:00
+    Cocoa,
+    /// WebKit/Chrome: microseconds since 1601-01-01T00:00:00
+    WebKit,
+    /// GPS time: seconds since 1980-01-06T00:00:00. GPS time doesn't observe
+    /// leap seconds, so results run ahead of UTC by the number of leap seconds
+    /// since 1980 (18 seconds as of 2017).
+    Gps,
+    /// NTP 64-bit timestamp: seconds since 1900-01-01T00:00:00 in the upper
+    /// 32 bits and a binary fraction of a second in the lower 32 bits. Only
+    /// NTP era 0 (up to 2036-02-07) is supported.
+    Ntp,
+    /// Spreadsheet serial date in the 1900 date system used by default in Excel
+    /// and LibreOffice: days since 1899-12-31, with the time of day as the
+    /// fractional part. Serial 60 is the nonexistent 1900-02-29 carried over
+    /// from Lotus 1-2-3 and is rejected; later serials are shifted back a day
+    /// to account for it. Times are rounded to the millisecond, the precision
+    /// spreadsheets store.
+    Excel1900,
+    /// Spreadsheet serial date in the 1904 date system used by older Excel
+    /// versions for Mac: days since 1904-01-01, with the time of day as the
+    /// fractional part. Times are rounded to the millisecond.
+    Excel1904,
+}
+
+impl Epoch {
+    /// The instant this epoch counts from
+    pub fn origin(self) -> NaiveDateTime {
+        let (y, m, d) = match self {
+            Epoch::Unix => (1970, 1, 1),
+            Epoch::FileTime | Epoch::WebKit => (1601, 1, 1),
+            Epoch::DotNetTicks => (1, 1, 1),
+            Epoch::Cocoa => (2001, 1, 1),
+            Epoch::Gps => (1980, 1, 6),
+            Epoch::Ntp => (1900, 1, 1),
+            Epoch::Excel1900 => (1899, 12, 30),
+            Epoch::Excel1904 => (1904, 1, 1),
+        };
+
+        // UNWRAP: All origins are valid dates
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    /// Convert a whole number of ticks in this epoch's native unit, returning
+    /// `None` if the result is outside of chrono's supported range.
+    pub fn to_naive(self, ticks: i128) -> Option<NaiveDateTime> {
+        self.nanos_to_naive(self.tick_nanos(ticks)?)
+    }
+
+    /// Convert a fractional number of ticks, such as a `CFAbsoluteTime` or a
+    /// spreadsheet cell value, returning `None` if the value isn't finite or
+    /// the result is outside of chrono's supported range.
+    pub fn to_naive_f64(self, ticks: f64) -> Option<NaiveDateTime> {
+        if !ticks.is_finite() || ticks.abs() >= 1e27 {
+            return None;
+        }
+
+        let nanos = match self {
+            Epoch::Ntp => self.tick_nanos(ticks as i128)?,
+            _ => (ticks * self.tick_nanos(1)? as f64).round() as i128,
+        };
+        self.nanos_to_naive(nanos)
+    }
+
+    fn nanos_to_naive(self, nanos: i128) -> Option<NaiveDateTime> {
+        let nanos = match self {
+            Epoch::Excel1900 | Epoch::Excel1904 => self.serial_nanos(nanos)?,
+            _ => nanos,
+        };
+        offset_nanos(self.origin(), nanos)
+    }
+
+    /// Map nanoseconds since a spreadsheet's nominal day zero to nanoseconds
+    /// since its origin
+    fn serial_nanos(self, nanos: i128) -> Option<i128> {
+        const DAY: i128 = 86_400 * 1_000_000_000;
+
+        if nanos < 0 {
+            return None;
+        }
+        let nanos = nanos.checked_add(500_000)? / 1_000_000 * 1_000_000;
+
+        if self == Epoch::Excel1900 {
+            match nanos / DAY {
+                60 => return None,
+                day if day < 60 => return Some(nanos + DAY),
+                _ => (),
+            }
+        }
+
+        Some(nanos)
+    }
+
+    /// Number of nanoseconds in `ticks`
+    fn tick_nanos(self, ticks: i128) -> Option<i128> {
+        match self {
+            Epoch::Unix | Epoch::Cocoa | Epoch::Gps => ticks.checked_mul(1_000_000_000),
+            Epoch::FileTime | Epoch::DotNetTicks => ticks.checked_mul(100),
+            Epoch::WebKit => ticks.checked_mul(1_000),
+            Epoch::Excel1900 | Epoch::Excel1904 => ticks.checked_mul(86_400 * 1_000_000_000),
+            Epoch::Ntp => {
+                if ticks < 0 || ticks > i128::from(u64::MAX) {
+                    return None;
+                }
+                let secs = ticks >> 32;
+                let frac = ((ticks & 0xFFFF_FFFF) * 1_000_000_000) >> 32;
+                Some(secs * 1_000_000_000 + frac)
+            }
+        }
+    }
+
+    /// Convert a decimal tick count split into sign, integer and fraction
+    /// digits. Fractions of an NTP tick are ignored.
+    pub(crate) fn decimal_to_naive(self, negative: bool, int: &str, frac: &str) -> Option<NaiveDateTime> {
+        let nanos = match self {
+            Epoch::Ntp => {
+                let ticks = parse_digits(int)?;
+                self.tick_nanos(if negative { -ticks } else { ticks })?
+            }
+            _ => decimal_nanos(negative, int, frac, self.tick_nanos(1)?)?,
+        };
+
+        self.nanos_to_naive(nanos)
+    }
+}
+
+/// Convert a GPS week number and time of week, as broadcast by GPS receivers,
+/// to a timestamp in the GPS time scale. Weeks are counted without rollover.
+pub fn from_gps_week(week: u32, seconds_of_week: u32, nanosecond: u32) -> Option<NaiveDateTime> {
+    if seconds_of_week >= 7 * 86_400 || nanosecond >= 1_000_000_000 {
+        return None;
+    }
+
+    let seconds = i128::from(week) * 7 * 86_400 + i128::from(seconds_of_week);
+    offset_nanos(Epoch::Gps.origin(), seconds * 1_000_000_000 + i128::from(nanosecond))
+}
+
+/// Parse a string of ASCII digits; anything longer than 27 digits can't fit
+/// in `i128` nanoseconds, let alone a `NaiveDateTime`.
+fn parse_digits(digits: &str) -> Option<i128> {
+    if digits.len() > 27 {
+        None
+    } else {
+        digits.parse::<i128>().ok()
+    }
+}
+
+/// Nanoseconds in a decimal number of ticks of `per_tick` nanoseconds each;
+/// fractional digits beyond nanosecond precision are truncated.
+pub(crate) fn decimal_nanos(negative: bool, int: &str, frac: &str, per_tick: i128) -> Option<i128> {
+    let nanos = parse_digits(int)?
+        .checked_mul(per_tick)?
+        .checked_add(fraction_nanos(frac, per_tick)?)?;
+    Some(if negative { -nanos } else { nanos })
+}
+
+/// Nanoseconds in `0.<frac>` ticks of `per_tick` nanoseconds each, truncated
+fn fraction_nanos(frac: &str, per_tick: i128) -> Option<i128> {
+    // Digits past the 18th can't affect a whole number of nanoseconds
+    let frac = &frac[..frac.len().min(18)];
+    if frac.is_empty() {
+        return Some(0);
+    }
+
+    let value = parse_digits(frac)?;
+    Some(value * per_tick / 10i128.pow(frac.len() as u32))
+}
+
+/// Offset `origin` by a (possibly negative) number of nanoseconds,
+/// returning `None` if the result is out of chrono's range.
+pub(crate) fn offset_nanos(origin: NaiveDateTime, nanos: i128) -> Option<NaiveDateTime> {
+    let secs = nanos.div_euclid(1_000_000_000);
+    let subsec = nanos.rem_euclid(1_000_000_000) as i64;
+
+    // chrono's range is roughly +/- 262,000 years, well under this bound, and
+    // staying below it keeps `Duration::seconds` from panicking.
+    if secs.abs() > 1 << 43 {
+        return None;
+    }
+
+    origin
+        .checked_add_signed(Duration::seconds(secs as i64))?
+        .checked_add_signed(Duration::nanoseconds(subsec))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use epoch::from_gps_week;
+    use epoch::Epoch;
+
+    #[test]
+    fn native_ticks() {
+        let expected = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+        assert_eq!(Epoch::Unix.to_naive(1_577_836_800), Some(expected));
+        assert_eq!(Epoch::FileTime.to_naive(132_223_104_000_000_000), Some(expected));
+        assert_eq!(Epoch::DotNetTicks.to_naive(637_134_336_000_000_000), Some(expected));
+        assert_eq!(Epoch::Cocoa.to_naive(599_529_600), Some(expected));
+        assert_eq!(Epoch::WebKit.to_naive(13_222_310_400_000_000), Some(expected));
+        assert_eq!(Epoch::Gps.to_naive(1_261_872_000), Some(expected));
+        assert_eq!(Epoch::Ntp.to_naive(3_786_825_600 << 32), Some(expected));
+    }
+
+    #[test]
+    fn ntp_fraction() {
+        let ticks = (3_786_825_600i128 << 32) | 0x8000_0000;
+        assert_eq!(
+            Epoch::Ntp.to_naive(ticks),
+            Some(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_milli_opt(0, 0, 0, 500).unwrap())
+        );
+        assert_eq!(Epoch::Ntp.to_naive(-1), None);
+    }
+
+    #[test]
+    fn gps_week() {
+        assert_eq!(
+            from_gps_week(2086, 259_200, 0),
+            Some(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap())
+        );
+        assert_eq!(from_gps_week(2086, 7 * 86_400, 0), None);
+    }
+
+    #[test]
+    fn decimal_ticks() {
+        assert_eq!(
+            Epoch::Cocoa.decimal_to_naive(true, "1", "25"),
+            Some(NaiveDate::from_ymd_opt(2000, 12, 31).unwrap().and_hms_milli_opt(23, 59, 58, 750).unwrap())
+        );
+        assert_eq!(
+            Epoch::FileTime.decimal_to_naive(false, "132223104000000000", "5"),
+            Some(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_nano_opt(0, 0, 0, 50).unwrap())
+        );
+    }
+
+    #[test]
+    fn spreadsheet_serials() {
+        let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(Epoch::Excel1900.to_naive(1), Some(ymd(1900, 1, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(Epoch::Excel1900.to_naive(59), Some(ymd(1900, 2, 28).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(Epoch::Excel1900.to_naive(60), None);
+        assert_eq!(Epoch::Excel1900.to_naive(61), Some(ymd(1900, 3, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(Epoch::Excel1900.to_naive(-1), None);
+        assert_eq!(
+            Epoch::Excel1900.to_naive_f64(45356.75),
+            Some(ymd(2024, 3, 5).and_hms_opt(18, 0, 0).unwrap())
+        );
+        assert_eq!(
+            Epoch::Excel1900.decimal_to_naive(false, "45356", "604166"),
+            Some(ymd(2024, 3, 5).and_hms_milli_opt(14, 29, 59, 942).unwrap())
+        );
+        assert_eq!(Epoch::Excel1904.to_naive(0), Some(ymd(1904, 1, 1).and_hms_opt(0, 0, 0).unwrap()));
+        assert_eq!(
+            Epoch::Excel1904.to_naive_f64(43894.5),
+            Some(ymd(2024, 3, 5).and_hms_opt(12, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn huge_spreadsheet_serials() {
+        assert_eq!(Epoch::Excel1900.to_naive_f64(1e26), None);
+        assert_eq!(Epoch::Excel1904.to_naive_f64(1e26), None);
+        assert_eq!(Epoch::Excel1900.to_naive(i128::MAX / (86_400 * 1_000_000_000)), None);
+    }
+}
@@ -0,0 +1,176 @@
+//! A byte-level scanner for canonical, fixed-width ISO 8601 / RFC 3339
+//! strings such as "2024-03-05T14:30:00.123456Z", which are common enough to
+//! be worth reading without the tokenizer. Anything it doesn't recognize is
+//! left for the general parser, including valid ISO strings in other layouts.
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+
+/// Read `YYYY-MM-DD`, optionally followed by `T` or a space and
+/// `HH:MM[:SS[.fffffffff]]`, then by `Z` or an offset like `+01:00`, `+0100`
+/// or `+01`. Returns the date and time with the offset in seconds.
+///
+/// Dates and times that don't exist return `None`, so the general parser can
+/// report the error (or clamp the day) exactly as it would otherwise.
+pub(crate) fn parse(timestr: &str) -> Option<(NaiveDateTime, Option<i32>)> {
+    let mut scanner = Scanner {
+        bytes: timestr.as_bytes(),
+        pos: 0,
+    };
+
+    let year = scanner.digits(4)?;
+    scanner.expect(b'-')?;
+    let month = scanner.digits(2)?;
+    scanner.expect(b'-')?;
+    let day = scanner.digits(2)?;
+    let date = NaiveDate::from_ymd_opt(year as i32, month, day)?;
+
+    if scanner.done() {
+        return Some((date.and_hms_opt(0, 0, 0)?, None));
+    }
+
+    match scanner.next()? {
+        b'T' | b' ' => {}
+        _ => return None,
+    }
+    let hour = scanner.digits(2)?;
+    scanner.expect(b':')?;
+    let minute = scanner.digits(2)?;
+
+    let mut second = 0;
+    let mut nanosecond = 0;
+    if scanner.peek() == Some(b':') {
+        scanner.pos += 1;
+        second = scanner.digits(2)?;
+
+        if let Some(b'.') | Some(b',') = scanner.peek() {
+            scanner.pos += 1;
+            nanosecond = scanner.fraction()?;
+        }
+    }
+    let time = NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond)?;
+
+    let offset = match scanner.next() {
+        None => None,
+        Some(b'Z') | Some(b'z') => Some(0),
+        Some(sign @ b'+') | Some(sign @ b'-') => {
+            let hours = scanner.digits(2)?;
+            let minutes = match scanner.peek() {
+                None => 0,
+                Some(b':') => {
+                    scanner.pos += 1;
+                    scanner.digits(2)?
+                }
+                Some(_) => scanner.digits(2)?,
+            };
+            let sign = if sign == b'+' { 1 } else { -1 };
+            Some(sign * (hours * 3600 + minutes * 60) as i32)
+        }
+        Some(_) => return None,
+    };
+
+    if scanner.done() {
+        Some((NaiveDateTime::new(date, time), offset))
+    } else {
+        None
+    }
+}
+
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn done(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).cloned()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.next()? == byte {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Exactly `count` ASCII digits
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.bytes.get(self.pos..self.pos + count)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.pos += count;
+        Some(digits.iter().fold(0, |acc, b| acc * 10 + u32::from(b - b'0')))
+    }
+
+    /// One to nine digits of a second, as nanoseconds. Longer fractions are
+    /// left to the general parser.
+    fn fraction(&mut self) -> Option<u32> {
+        let len = self.bytes[self.pos..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if len == 0 || len > 9 {
+            return None;
+        }
+        let value = self.digits(len)?;
+        Some(value * 10u32.pow(9 - len as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn reads_canonical_layouts() {
+        let date = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        assert_eq!(parse("2024-03-05"), Some((date.and_hms_opt(0, 0, 0).unwrap(), None)));
+        assert_eq!(
+            parse("2024-03-05T14:30:00.123456Z"),
+            Some((date.and_hms_micro_opt(14, 30, 0, 123_456).unwrap(), Some(0)))
+        );
+        assert_eq!(
+            parse("2024-03-05 14:30-05:30"),
+            Some((date.and_hms_opt(14, 30, 0).unwrap(), Some(-19_800)))
+        );
+        assert_eq!(
+            parse("2024-03-05T14:30:00,5+0100"),
+            Some((date.and_hms_milli_opt(14, 30, 0, 500).unwrap(), Some(3600)))
+        );
+    }
+
+    #[test]
+    fn leaves_the_rest_to_the_parser() {
+        for timestr in &[
+            "",
+            "2024-3-05",
+            "2024-03-05T",
+            "2024-03-05T14",
+            "2024-03-05t14:30",
+            "2024-03-05T14:30:00.",
+            "2024-03-05T14:30:00.1234567891",
+            "2024-03-05T14:30:00+1",
+            "2024-03-05T14:30:00 +01:00",
+            "2024-03-05T14:30:00+01:00:00",
+            "2024-02-30",
+            "2024-03-05T24:00",
+            "２０２４-03-05",
+        ] {
+            assert_eq!(parse(timestr), None, "Scanned '{}'", timestr);
+        }
+    }
+}
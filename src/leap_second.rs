@@ -0,0 +1,80 @@
+//! The leap seconds announced by the IERS so far, for checking that a time
+//! with a second of 60 really happened.
+
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDateTime;
+use chrono::Timelike;
+
+/// UTC dates that ended with a leap second at 23:59:60. None have been
+/// removed, and none are scheduled up to the end of 2026.
+const LEAP_SECONDS: &[(i32, u32, u32)] = &[
+    (1972, 6, 30),
+    (1972, 12, 31),
+    (1973, 12, 31),
+    (1974, 12, 31),
+    (1975, 12, 31),
+    (1976, 12, 31),
+    (1977, 12, 31),
+    (1978, 12, 31),
+    (1979, 12, 31),
+    (1981, 6, 30),
+    (1982, 6, 30),
+    (1983, 6, 30),
+    (1985, 6, 30),
+    (1987, 12, 31),
+    (1989, 12, 31),
+    (1990, 12, 31),
+    (1992, 6, 30),
+    (1993, 6, 30),
+    (1994, 6, 30),
+    (1995, 12, 31),
+    (1997, 6, 30),
+    (1998, 12, 31),
+    (2005, 12, 31),
+    (2008, 12, 31),
+    (2012, 6, 30),
+    (2015, 6, 30),
+    (2016, 12, 31),
+];
+
+/// Whether `local`, a leap second in chrono's representation (second 59 with
+/// a nanosecond of at least 1_000_000_000), is in the table once shifted to
+/// UTC from an offset of `offset` seconds
+pub(crate) fn is_known_leap_second(local: NaiveDateTime, offset: i32) -> bool {
+    let utc = local
+        .with_nanosecond(local.nanosecond() % 1_000_000_000)
+        .and_then(|regular| regular.checked_sub_signed(Duration::seconds(i64::from(offset))));
+
+    match utc {
+        Some(utc) if (utc.hour(), utc.minute(), utc.second()) == (23, 59, 59) => {
+            LEAP_SECONDS.contains(&(utc.year(), utc.month(), utc.day()))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_known_leap_second;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn leap_seconds() {
+        let leap = |y, m, d, h, min| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_nano_opt(h, min, 59, 1_500_000_000)
+                .unwrap()
+        };
+
+        assert!(is_known_leap_second(leap(2016, 12, 31, 23, 59), 0));
+        assert!(is_known_leap_second(leap(1972, 6, 30, 23, 59), 0));
+        assert!(is_known_leap_second(leap(2017, 1, 1, 0, 59), 3600));
+        assert!(is_known_leap_second(leap(2015, 6, 30, 19, 59), -4 * 3600));
+
+        assert!(!is_known_leap_second(leap(2017, 12, 31, 23, 59), 0));
+        assert!(!is_known_leap_second(leap(2016, 12, 31, 23, 59), 3600));
+        assert!(!is_known_leap_second(leap(2016, 12, 31, 22, 59), 0));
+    }
+}
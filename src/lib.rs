@@ -62,9 +62,13 @@
 //!
 //! Further examples can be found in the `examples` directory on international usage.
 //!
+//! Parsing never panics: any string that can't be understood, however
+//! malformed, gives back a `ParseError`. Property tests feeding random and
+//! corrupted strings to every entry point keep it that way.
+//!
 //! # Usage
 //!
-//! `dtparse` requires a minimum Rust version of 1.28 to build, but is tested on Windows, OSX,
+//! `dtparse` requires a minimum Rust version of 1.82 to build, but is tested on Windows, OSX,
 //! BSD, Linux, and WASM. The build is also compiled against the iOS and Android SDK's, but is not
 //! tested against them.
 //!
@@ -74,11 +78,13 @@
 extern crate lazy_static;
 
 extern crate chrono;
-extern crate num_traits;
+#[cfg(feature = "rust_decimal")]
 extern crate rust_decimal;
 
 #[cfg(test)]
 extern crate base64;
+#[cfg(test)]
+extern crate proptest;
 
 use chrono::Datelike;
 use chrono::Duration;
@@ -88,35 +94,46 @@ use chrono::NaiveDate;
 use chrono::NaiveDateTime;
 use chrono::NaiveTime;
 use chrono::Timelike;
-use num_traits::cast::ToPrimitive;
-use rust_decimal::Decimal;
+use chrono::TimeZone;
+#[cfg(feature = "rust_decimal")]
 use rust_decimal::Error as DecimalError;
+use std::borrow::Cow;
 use std::cmp::min;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::error::Error;
 use std::fmt;
+use std::fmt::Write;
 use std::num::ParseIntError;
-use std::str::FromStr;
 use std::vec::Vec;
 
+pub mod calendar;
+pub mod column;
+pub mod epoch;
+mod iso;
+mod leap_second;
+mod number;
 mod tokenize;
-mod weekday;
+mod vocabulary;
 
 #[cfg(test)]
 mod tests;
 
+use epoch::decimal_nanos;
+use epoch::offset_nanos;
+use epoch::Epoch;
+use number::Number;
 use tokenize::Tokenizer;
-use weekday::day_of_week;
-use weekday::DayOfWeek;
+use calendar::Calendar;
+use vocabulary::Marker;
+use vocabulary::Vocabulary;
 
 lazy_static! {
-    static ref ZERO: Decimal = Decimal::new(0, 0);
-    static ref ONE: Decimal = Decimal::new(1, 0);
-    static ref TWENTY_FOUR: Decimal = Decimal::new(24, 0);
-    static ref SIXTY: Decimal = Decimal::new(60, 0);
     static ref DEFAULT_PARSER: Parser = Parser::default();
 }
 
+#[cfg(feature = "rust_decimal")]
 impl From<DecimalError> for ParseError {
     fn from(err: DecimalError) -> Self {
         ParseError::InvalidNumeric(format!("{}", err))
@@ -129,8 +146,10 @@ impl From<ParseIntError> for ParseError {
     }
 }
 
-/// Potential errors that come up when trying to parse time strings
+/// Potential errors that come up when trying to parse time strings.
+/// Variants may be added in minor releases.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ParseError {
     /// Attempted to specify "AM" or "PM" without indicating an hour
     AmPmWithoutHour,
@@ -151,6 +170,20 @@ pub enum ParseError {
     YearMonthDayError(&'static str),
     /// Parser unable to find any date/time-related content in the supplied string
     NoDate,
+    /// No single strftime pattern describes the string given to `Parser::infer_format`
+    NoFormatPattern(&'static str),
+    /// A value in a batch can't be read in any date order that fits the values
+    /// before it; "03/25/2024" and "25/03/2024" can't share a column
+    InconsistentDateOrder(String),
+    /// The date could be read in more than one of these orders with different
+    /// results, and ambiguous dates were set to be errors with
+    /// `Parser::with_ambiguous_date_errors`
+    AmbiguousDate(Vec<DateOrder>),
+    /// A fuzzy parse scored below the minimum set with
+    /// `Parser::with_min_confidence`; carries the score
+    LowConfidence(f64),
+    /// The string broke one of the `Limits` set with `Parser::with_limits`
+    LimitExceeded(&'static str),
 }
 
 impl fmt::Display for ParseError {
@@ -163,18 +196,13 @@ impl Error for ParseError {}
 
 type ParseResult<I> = Result<I, ParseError>;
 
-pub(crate) fn tokenize(parse_string: &str) -> Vec<String> {
-    let tokenizer = Tokenizer::new(parse_string);
-    tokenizer.collect()
-}
-
 /// Utility function for `ParserInfo` that helps in constructing
 /// the attributes that make up the `ParserInfo` container
 pub fn parse_info(vec: Vec<Vec<&str>>) -> HashMap<String, usize> {
     let mut m = HashMap::new();
 
     if vec.len() == 1 {
-        for (i, val) in vec.get(0).unwrap().iter().enumerate() {
+        for (i, val) in vec[0].iter().enumerate() {
             m.insert(val.to_lowercase(), i);
         }
     } else {
@@ -198,6 +226,12 @@ pub fn parse_info(vec: Vec<Vec<&str>>) -> HashMap<String, usize> {
 /// - `utczone`: Tokens indicating a UTC-timezone string
 /// - `pertain`: Tokens indicating a "belongs to" relationship; in English this is just "of"
 /// - `tzoffset`:
+/// - `julian_day`: Tokens introducing a Julian Day, Modified Julian Day or Reduced Julian Day number
+/// - `era`: Eras before and after 1 AD, which may be several words like "av. J.-C."
+/// - `calendar_style`: Markers for dates in the Julian (Old Style) or Gregorian (New Style) calendar
+/// - `regnal_eras`: Eras counting years from an accession, like Japan's imperial eras
+/// - `first_year`: Words for the first year of a regnal era, like "元年"
+/// - `offset_calendar`: A calendar numbering years from another epoch, like the Thai Buddhist Era
 /// - `dayfirst`: Upon encountering an ambiguous date, treat the first value as the day
 /// - `yearfirst`: Upon encountering an ambiguous date, treat the first value as the year
 /// - `year`: The current year
@@ -223,6 +257,30 @@ pub struct ParserInfo {
     pub pertain: HashMap<String, usize>,
     /// Map of timezone names to their offset in seconds
     pub tzoffset: HashMap<String, usize>,
+    /// Tokens introducing a Julian Day (JD), Modified Julian Day (MJD) or
+    /// Reduced Julian Day (RJD) number, in that order
+    pub julian_day: HashMap<String, usize>,
+    /// Eras counting years before 1 AD, then eras counting years from it.
+    /// Years are numbered astronomically, so 1 BC is year 0 and 44 BC is
+    /// year -43. Punctuation and spaces between the words of an era are
+    /// ignored, so "B.C." also matches "B. C."
+    pub era: HashMap<String, usize>,
+    /// Markers for Old Style dates, which are in the Julian calendar, then
+    /// for New Style dates, in the Gregorian one. Like eras, they may be
+    /// several words, such as "O.S." or "Old Style".
+    pub calendar_style: HashMap<String, usize>,
+    /// Eras counting years from an accession, in the order they began. A
+    /// date starting with one of their names, such as "令和6年3月5日" or
+    /// "R6.3.5", is in the era's calendar and must fall within it. None by
+    /// default; `with_japanese_eras` adds Japan's.
+    pub regnal_eras: Vec<RegnalEra>,
+    /// Words for the first year of a regnal era, which may be written
+    /// straight after the era's name, as in "令和元年"
+    pub first_year: HashMap<String, usize>,
+    /// A calendar whose years are a fixed offset from Gregorian ones, like
+    /// the Thai Buddhist Era. None by default; `with_thai_buddhist_years`
+    /// and `with_minguo_years` set one.
+    pub offset_calendar: Option<OffsetCalendar>,
     /// For ambiguous year/month/day values, and `dayfirst` was not specified as
     /// an argument to `Parser`, treat the first observed value as the day.
     pub dayfirst: bool,
@@ -244,7 +302,7 @@ impl Default for ParserInfo {
 
         ParserInfo {
             jump: parse_info(vec![vec![
-                " ", ".", ",", ";", "-", "/", "'", "at", "on", "and", "ad", "m", "t", "of", "st",
+                " ", ".", ",", ";", "-", "/", "'", "at", "on", "and", "m", "t", "of", "st",
                 "nd", "rd", "th",
             ]]),
             weekday: parse_info(vec![
@@ -279,6 +337,15 @@ impl Default for ParserInfo {
             utczone: parse_info(vec![vec!["UTC", "GMT", "Z"]]),
             pertain: parse_info(vec![vec!["of"]]),
             tzoffset: parse_info(vec![vec![]]),
+            julian_day: parse_info(vec![vec!["JD"], vec!["MJD"], vec!["RJD"]]),
+            era: parse_info(vec![
+                vec!["BC", "BCE", "B.C.", "B.C.E."],
+                vec!["AD", "CE", "A.D.", "C.E."],
+            ]),
+            calendar_style: parse_info(vec![vec!["O.S.", "Old Style"], vec!["N.S.", "New Style"]]),
+            regnal_eras: Vec::new(),
+            first_year: parse_info(vec![vec![]]),
+            offset_calendar: None,
             dayfirst: false,
             yearfirst: false,
             year,
@@ -287,66 +354,101 @@ impl Default for ParserInfo {
     }
 }
 
-impl ParserInfo {
-    fn jump_index(&self, name: &str) -> bool {
-        self.jump.contains_key(&name.to_lowercase())
-    }
-
-    fn weekday_index(&self, name: &str) -> Option<usize> {
-        self.weekday.get(&name.to_lowercase()).cloned()
-    }
+/// An era counting years from an emperor's accession, so the first year of
+/// Japan's Reiwa era, which began on 2019-05-01, is 2019
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegnalEra {
+    /// Names of the era, like "令和", "Reiwa" and "R"
+    pub names: Vec<String>,
+    /// Day the era began. It lasts until the next era in
+    /// `ParserInfo::regnal_eras` begins.
+    pub start: NaiveDate,
+}
 
-    fn month_index(&self, name: &str) -> Option<usize> {
-        self.months.get(&name.to_lowercase()).map(|u| u + 1)
+impl RegnalEra {
+    fn new(names: &[&str], year: i32, month: u32, day: u32) -> Self {
+        RegnalEra {
+            names: names.iter().map(|&name| name.to_owned()).collect(),
+            start: NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+        }
     }
+}
 
-    fn hms_index(&self, name: &str) -> Option<usize> {
-        self.hms.get(&name.to_lowercase()).cloned()
-    }
+/// A calendar counting years from a different epoch than the Gregorian
+/// one, such as the Thai Buddhist Era, whose year 2567 is 2024
+#[derive(Debug, Clone, PartialEq)]
+pub struct OffsetCalendar {
+    /// Markers for years in this calendar, like "B.E." or "民國". Like eras,
+    /// they may be several words, and come just before or after the year.
+    pub markers: HashMap<String, usize>,
+    /// Added to a year in this calendar to give the Gregorian year
+    pub offset: i32,
+    /// Whether years written without a marker are in this calendar too.
+    /// Two-digit years are then still read as Gregorian ones, since they're
+    /// completed from the current year.
+    pub unmarked: bool,
+}
 
-    fn ampm_index(&self, name: &str) -> Option<bool> {
-        if let Some(v) = self.ampm.get(&name.to_lowercase()) {
-            // Python technically uses numbers here, but given that the numbers are
-            // only 0 and 1, it's easier to use booleans
-            Some(*v == 1)
-        } else {
-            None
-        }
+impl ParserInfo {
+    /// Read years in the Thai Buddhist Era, marked with "B.E." or "พ.ศ." or
+    /// not at all, so "5 March 2567" is in 2024
+    pub fn with_thai_buddhist_years(mut self) -> Self {
+        self.offset_calendar = Some(OffsetCalendar {
+            markers: parse_info(vec![vec!["BE", "B.E.", "พ.ศ."]]),
+            offset: -543,
+            unmarked: true,
+        });
+        self
     }
 
-    fn pertain_index(&self, name: &str) -> bool {
-        self.pertain.contains_key(&name.to_lowercase())
+    /// Read years in Taiwan's Minguo calendar, counted from the founding of
+    /// the Republic of China in 1912, so "民國113年3月5日" and "113/03/05"
+    /// are in 2024. Adds "年", "月" and "日" after the year, month and day.
+    pub fn with_minguo_years(mut self) -> Self {
+        self.offset_calendar = Some(OffsetCalendar {
+            markers: parse_info(vec![vec!["民國", "民国", "中華民國", "中华民国", "ROC"]]),
+            offset: 1911,
+            unmarked: true,
+        });
+        self.add_cjk_units();
+        self
     }
 
-    fn utczone_index(&self, name: &str) -> bool {
-        self.utczone.contains_key(&name.to_lowercase())
+    /// Add Japan's imperial eras from Meiji to Reiwa, in kanji, romaji and
+    /// as initials, with "元年" for their first year and "年", "月" and "日"
+    /// after the year, month and day. Japan kept a lunisolar calendar until
+    /// 1873, so early Meiji dates are read as if they were Gregorian.
+    pub fn with_japanese_eras(mut self) -> Self {
+        self.regnal_eras = vec![
+            RegnalEra::new(&["明治", "Meiji", "M"], 1868, 10, 23),
+            RegnalEra::new(&["大正", "Taisho", "Taishō", "T"], 1912, 7, 30),
+            RegnalEra::new(&["昭和", "Showa", "Shōwa", "S"], 1926, 12, 25),
+            RegnalEra::new(&["平成", "Heisei", "H"], 1989, 1, 8),
+            RegnalEra::new(&["令和", "Reiwa", "R"], 2019, 5, 1),
+        ];
+        self.first_year.insert("元年".to_owned(), 0);
+        self.add_cjk_units();
+        self
     }
 
-    fn tzoffset_index(&self, name: &str) -> Option<usize> {
-        if self.utczone.contains_key(&name.to_lowercase()) {
-            Some(0)
-        } else {
-            self.tzoffset.get(&name.to_lowercase()).cloned()
+    /// Skip the "年", "月" and "日" written after years, months and days in
+    /// Chinese and Japanese
+    fn add_cjk_units(&mut self) {
+        for unit in &["年", "月", "日"] {
+            self.jump.insert((*unit).to_owned(), 0);
         }
     }
 
     fn convertyear(&self, year: i32, century_specified: bool) -> i32 {
-        let mut year = year;
-
         if year < 100 && !century_specified {
-            year += self.century;
-            if year >= self.year + 50 {
-                year -= 100;
-            } else if year < self.year - 50 {
-                year += 100
-            }
+            complete_year(year, self.year, self.century)
+        } else {
+            year
         }
-
-        year
     }
 
     // TODO: Should this be moved elsewhere?
-    fn validate(&self, res: &mut ParsingResult) -> bool {
+    fn validate(&self, res: &mut ParsingResult, vocab: &Vocabulary) -> bool {
         if let Some(y) = res.year {
             res.year = Some(self.convertyear(y, res.century_specified))
         };
@@ -358,7 +460,7 @@ impl ParserInfo {
             res.tzoffset = Some(0);
         } else if res.tzoffset != Some(0)
             && res.tzname.is_some()
-            && self.utczone_index(res.tzname.as_ref().unwrap())
+            && vocab.lookup(res.tzname.as_ref().unwrap()).utczone
         {
             res.tzoffset = Some(0);
         }
@@ -367,23 +469,42 @@ impl ParserInfo {
     }
 }
 
+/// The year ending in two-digit `year` within 50 years of `current`, whose
+/// century starts in `century`
+fn complete_year(year: i32, current: i32, century: i32) -> i32 {
+    let year = year + century;
+    if year >= current + 50 {
+        year - 100
+    } else if year < current - 50 {
+        year + 100
+    } else {
+        year
+    }
+}
+
 fn days_in_month(year: i32, month: i32) -> Result<u32, ParseError> {
-    let leap_year = match year % 4 {
-        0 => year % 400 != 0,
-        _ => false,
-    };
+    u32::try_from(month)
+        .ok()
+        .and_then(|month| calendar::days_in_month(year, month))
+        .ok_or(ParseError::ImpossibleTimestamp("Invalid month"))
+}
 
-    match month {
-        2 => {
-            if leap_year {
-                Ok(29)
-            } else {
-                Ok(28)
-            }
+/// Days in `month` of `year` in `calendar`, or in the Gregorian calendar
+/// without one. Without a year, the most days the month can have.
+fn month_days(calendar: Option<&dyn Calendar>, year: Option<i32>, month: i32) -> Option<u32> {
+    let calendar = match calendar {
+        Some(calendar) => calendar,
+        // 2000 was a leap year
+        None => return days_in_month(year.unwrap_or(2000), month).ok(),
+    };
+    let month = u32::try_from(month).ok()?;
+    match year {
+        Some(year) => calendar.days_in_month(year, month),
+        None => {
+            // Leap year cycles are at most 33 years long, in the Persian calendar
+            let start = NaiveDate::from_ymd_opt(2000, 1, 1).map_or(2000, |d| calendar.ymd(d).0);
+            (start..start + 33).filter_map(|year| calendar.days_in_month(year, month)).max()
         }
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => Ok(31),
-        4 | 6 | 9 | 11 => Ok(30),
-        _ => Err(ParseError::ImpossibleTimestamp("Invalid month")),
     }
 }
 
@@ -394,13 +515,20 @@ enum YMDLabel {
     Day,
 }
 
-#[derive(Debug, Default)]
+/// Positions of the year, month and day among the values collected in a `YMD`
+type YmdPositions = (Option<usize>, Option<usize>, Option<usize>);
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Default, Clone)]
 struct YMD {
     _ymd: Vec<i32>, // TODO: This seems like a super weird way to store things
     century_specified: bool,
     dstridx: Option<usize>,
     mstridx: Option<usize>,
     ystridx: Option<usize>,
+    /// Whether the values were written ISO 8601 style, as a four-digit year
+    /// followed by numbers joined with '-', which is always year-month-day
+    iso_order: bool,
 }
 
 impl YMD {
@@ -408,20 +536,17 @@ impl YMD {
         self._ymd.len()
     }
 
-    fn could_be_day(&self, val: i32) -> bool {
+    /// Whether `val` could be the day of the month, with month lengths from
+    /// `calendar`, or the Gregorian calendar without one
+    fn could_be_day(&self, val: i32, calendar: Option<&dyn Calendar>) -> bool {
         if self.dstridx.is_some() {
             false
-        } else if self.mstridx.is_none() {
-            (1 <= val) && (val <= 31)
-        } else if self.ystridx.is_none() {
-            // UNWRAP: Earlier condition catches mstridx missing
-            let month = self._ymd[self.mstridx.unwrap()];
-            1 <= val && (val <= days_in_month(2000, month).unwrap() as i32)
+        } else if let Some(mstridx) = self.mstridx {
+            let month = self._ymd[mstridx];
+            let year = self.ystridx.map(|y| self._ymd[y]);
+            1 <= val && month_days(calendar, year, month).is_some_and(|days| val <= days as i32)
         } else {
-            // UNWRAP: Earlier conditions prevent us from unsafely unwrapping
-            let month = self._ymd[self.mstridx.unwrap()];
-            let year = self._ymd[self.ystridx.unwrap()];
-            1 <= val && (val <= days_in_month(year, month).unwrap() as i32)
+            (1..=31).contains(&val)
         }
     }
 
@@ -430,7 +555,7 @@ impl YMD {
 
         // Python auto-detects strings using the '__len__' function here.
         // We instead take in both and handle as necessary.
-        if Decimal::from_str(token).is_ok() && token.len() > 2 {
+        if Number::parse(token).is_some() && token.len() > 2 {
             self.century_specified = true;
             match label {
                 None | Some(YMDLabel::Year) => label = Some(YMDLabel::Year),
@@ -487,7 +612,7 @@ impl YMD {
     fn resolve_from_stridxs(
         &mut self,
         strids: &mut HashMap<YMDLabel, usize>,
-    ) -> ParseResult<(Option<i32>, Option<i32>, Option<i32>)> {
+    ) -> ParseResult<YmdPositions> {
         if self._ymd.len() == 3 && strids.len() == 2 {
             let missing_key = if !strids.contains_key(&YMDLabel::Year) {
                 YMDLabel::Year
@@ -516,18 +641,76 @@ impl YMD {
         }
 
         Ok((
-            strids.get(&YMDLabel::Year).map(|i| self._ymd[*i]),
-            strids.get(&YMDLabel::Month).map(|i| self._ymd[*i]),
-            strids.get(&YMDLabel::Day).map(|i| self._ymd[*i]),
+            strids.get(&YMDLabel::Year).cloned(),
+            strids.get(&YMDLabel::Month).cloned(),
+            strids.get(&YMDLabel::Day).cloned(),
         ))
     }
 
+    /// Whether the collected values make a valid date when read in `order`,
+    /// or `None` if the order doesn't matter for them: there's a month name,
+    /// or there aren't two or three numbers to arrange. Months are checked
+    /// against `calendar`, or the Gregorian calendar without one.
+    fn fits_order(&self, order: DateOrder, calendar: Option<&dyn Calendar>) -> Option<bool> {
+        if self.mstridx.is_some() {
+            return None;
+        }
+        let (year, month, day) = order.positions(self.len())?;
+        if year.is_none() && self.ystridx.is_some() {
+            return None;
+        }
+
+        let labels_agree =
+            self.ystridx.is_none_or(|y| year == Some(y)) && self.dstridx.is_none_or(|d| d == day);
+        let year_value = year.map(|y| self._ymd[y]);
+        let month_value = self._ymd[month];
+        let day_value = self._ymd[day];
+
+        Some(
+            labels_agree
+                && day_value >= 1
+                && month_days(calendar, year_value, month_value).is_some_and(|d| day_value <= d as i32),
+        )
+    }
+
+    /// The valid ways of reading the collected values, as the order and the
+    /// positions of the year, month and day it gives. Orders are tried
+    /// starting with `preferred`, and those that give the same date as an
+    /// earlier one are left out. Dates written ISO 8601 style are only read
+    /// year-month-day. Empty if the order doesn't matter or no order gives a
+    /// valid date.
+    fn readings(
+        &self,
+        preferred: DateOrder,
+        calendar: Option<&dyn Calendar>,
+    ) -> Vec<(DateOrder, YmdPositions)> {
+        let value = |idx: Option<usize>| idx.map(|i| self._ymd[i]);
+        let mut readings: Vec<(DateOrder, YmdPositions)> = Vec::new();
+
+        for order in DateOrder::ranked(preferred) {
+            if self.fits_order(order, calendar) != Some(true) || (self.iso_order && order == DateOrder::YearDayMonth) {
+                continue;
+            }
+            let (year, month, day) = order.positions(self.len()).unwrap();
+            let positions = (year, Some(month), Some(day));
+            let duplicate = readings.iter().any(|&(_, (y, m, d))| {
+                (value(y), value(m), value(d)) == (value(year), value(Some(month)), value(Some(day)))
+            });
+            if !duplicate {
+                readings.push((order, positions));
+            }
+        }
+        readings
+    }
+
+    /// Decide which of the collected values are the year, month and day,
+    /// returning their positions in `_ymd`
     #[allow(clippy::needless_return)]
     fn resolve_ymd(
         &mut self,
         yearfirst: bool,
         dayfirst: bool,
-    ) -> ParseResult<(Option<i32>, Option<i32>, Option<i32>)> {
+    ) -> ParseResult<YmdPositions> {
         let len_ymd = self._ymd.len();
 
         let mut strids: HashMap<YMDLabel, usize> = HashMap::new();
@@ -549,46 +732,42 @@ impl YMD {
 
         match (len_ymd, self.mstridx) {
             (1, Some(val)) | (2, Some(val)) => {
-                let other = if len_ymd == 1 {
-                    self._ymd[0]
-                } else {
-                    self._ymd[1 - val]
-                };
-                if other > 31 {
-                    return Ok((Some(other), Some(self._ymd[val]), None));
+                let other = if len_ymd == 1 { 0 } else { 1 - val };
+                if self._ymd[other] > 31 {
+                    return Ok((Some(other), Some(val), None));
                 }
-                return Ok((None, Some(self._ymd[val]), Some(other)));
+                return Ok((None, Some(val), Some(other)));
             }
             (2, None) => {
                 if self._ymd[0] > 31 {
-                    return Ok((Some(self._ymd[0]), Some(self._ymd[1]), None));
+                    return Ok((Some(0), Some(1), None));
                 }
                 if self._ymd[1] > 31 {
-                    return Ok((Some(self._ymd[1]), Some(self._ymd[0]), None));
+                    return Ok((Some(1), Some(0), None));
                 }
                 if dayfirst && self._ymd[1] <= 12 {
-                    return Ok((None, Some(self._ymd[1]), Some(self._ymd[0])));
+                    return Ok((None, Some(1), Some(0)));
                 }
-                return Ok((None, Some(self._ymd[0]), Some(self._ymd[1])));
+                return Ok((None, Some(0), Some(1)));
             }
             (3, Some(0)) => {
                 if self._ymd[1] > 31 {
-                    return Ok((Some(self._ymd[1]), Some(self._ymd[0]), Some(self._ymd[2])));
+                    return Ok((Some(1), Some(0), Some(2)));
                 }
-                return Ok((Some(self._ymd[2]), Some(self._ymd[0]), Some(self._ymd[1])));
+                return Ok((Some(2), Some(0), Some(1)));
             }
             (3, Some(1)) => {
                 if self._ymd[0] > 31 || (yearfirst && self._ymd[2] <= 31) {
-                    return Ok((Some(self._ymd[0]), Some(self._ymd[1]), Some(self._ymd[2])));
+                    return Ok((Some(0), Some(1), Some(2)));
                 }
-                return Ok((Some(self._ymd[2]), Some(self._ymd[1]), Some(self._ymd[0])));
+                return Ok((Some(2), Some(1), Some(0)));
             }
             (3, Some(2)) => {
                 // It was in the original docs, so: WTF!?
                 if self._ymd[1] > 31 {
-                    return Ok((Some(self._ymd[2]), Some(self._ymd[1]), Some(self._ymd[0])));
+                    return Ok((Some(2), Some(1), Some(0)));
                 }
-                return Ok((Some(self._ymd[0]), Some(self._ymd[2]), Some(self._ymd[1])));
+                return Ok((Some(0), Some(2), Some(1)));
             }
             (3, None) => {
                 if self._ymd[0] > 31
@@ -596,13 +775,13 @@ impl YMD {
                     || (yearfirst && self._ymd[1] <= 12 && self._ymd[2] <= 31)
                 {
                     if dayfirst && self._ymd[2] <= 12 {
-                        return Ok((Some(self._ymd[0]), Some(self._ymd[2]), Some(self._ymd[1])));
+                        return Ok((Some(0), Some(2), Some(1)));
                     }
-                    return Ok((Some(self._ymd[0]), Some(self._ymd[1]), Some(self._ymd[2])));
+                    return Ok((Some(0), Some(1), Some(2)));
                 } else if self._ymd[0] > 12 || (dayfirst && self._ymd[1] <= 12) {
-                    return Ok((Some(self._ymd[2]), Some(self._ymd[1]), Some(self._ymd[0])));
+                    return Ok((Some(2), Some(1), Some(0)));
                 }
-                return Ok((Some(self._ymd[2]), Some(self._ymd[0]), Some(self._ymd[1])));
+                return Ok((Some(2), Some(0), Some(1)));
             }
             (_, _) => {
                 return Ok((None, None, None));
@@ -611,7 +790,7 @@ impl YMD {
     }
 }
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Default, Debug, PartialEq, Clone)]
 struct ParsingResult {
     year: Option<i32>,
     month: Option<i32>,
@@ -620,12 +799,104 @@ struct ParsingResult {
     hour: Option<i32>,
     minute: Option<i32>,
     second: Option<i32>,
+    /// Up to 1_000_000_000 when the fraction rounded up to a whole second
     nanosecond: Option<i64>,
+    /// Digits written after the seconds' decimal point
+    fraction_digits: Option<usize>,
     tzname: Option<String>,
     tzoffset: Option<i32>,
     ampm: Option<bool>,
+    /// `true` for years before 1 AD
+    era: Option<bool>,
+    /// `true` for dates marked Old Style, `false` for New Style
+    julian: Option<bool>,
+    /// Index into `ParserInfo::regnal_eras` of the era the year counts from
+    regnal_era: Option<usize>,
+    /// Whether the year was marked as in `ParserInfo::offset_calendar`
+    offset_year: bool,
     century_specified: bool,
     any_unused_tokens: Vec<String>,
+    spans: Vec<FieldSpan>,
+    confidence: f64,
+}
+
+/// Everything `Parser::scan_tokens` learned from a string, before the
+/// year, month and day have been told apart
+#[derive(Clone)]
+struct Scanned<'a> {
+    res: ParsingResult,
+    ymd: YMD,
+    /// Tokens of the string, borrowed from it where possible
+    tokens: Vec<Cow<'a, str>>,
+    skipped_idxs: Vec<usize>,
+}
+
+/// Which field of the timestamp a piece of a token was parsed into,
+/// as used by `Parser::infer_format`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FormatField {
+    /// Position in `YMD`, before it has been resolved to a year, month or day
+    Ymd(usize),
+    Year,
+    Month,
+    Day,
+    Weekday,
+    Hour,
+    Minute,
+    Second,
+    Fraction,
+    AmPm,
+    /// Timezone offset, with or without a colon between hours and minutes
+    Offset(bool),
+    /// Part of a field started by an earlier token
+    Consumed,
+    /// Parsed, but in a way a strftime pattern can't express
+    Unsupported,
+}
+
+impl FormatField {
+    fn specifier(self, text: &str, twelve_hour: bool) -> ParseResult<&'static str> {
+        let padded = |unpadded, padded| if text.len() == 1 { unpadded } else { padded };
+        let starts_numeric = text.starts_with(|c: char| c.is_ascii_digit());
+
+        match self {
+            FormatField::Year if text.len() == 4 => Ok("%Y"),
+            FormatField::Year if text.len() == 2 => Ok("%y"),
+            FormatField::Month if starts_numeric => Ok(padded("%-m", "%m")),
+            FormatField::Month if text.len() == 3 => Ok("%b"),
+            FormatField::Month => Ok("%B"),
+            FormatField::Day => Ok(padded("%-d", "%d")),
+            FormatField::Weekday if text.len() == 3 => Ok("%a"),
+            FormatField::Weekday => Ok("%A"),
+            FormatField::Hour if twelve_hour => Ok(padded("%-I", "%I")),
+            FormatField::Hour => Ok(padded("%-H", "%H")),
+            FormatField::Minute => Ok(padded("%-M", "%M")),
+            FormatField::Second => Ok(padded("%-S", "%S")),
+            FormatField::Fraction => match text.len() {
+                3 => Ok("%3f"),
+                6 => Ok("%6f"),
+                9 => Ok("%9f"),
+                _ => Err(ParseError::NoFormatPattern(
+                    "Fractional seconds must have 3, 6 or 9 digits",
+                )),
+            },
+            FormatField::AmPm if text.chars().all(|c| c.is_ascii_uppercase()) => Ok("%p"),
+            FormatField::AmPm if text.chars().all(|c| c.is_ascii_lowercase()) => Ok("%P"),
+            FormatField::Offset(true) => Ok("%:z"),
+            FormatField::Offset(false) => Ok("%z"),
+            FormatField::Consumed => Ok(""),
+            _ => Err(ParseError::NoFormatPattern("Token has no strftime equivalent")),
+        }
+    }
+}
+
+/// Bytes `start..end` of token number `token` were parsed into `field`
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FieldSpan {
+    token: usize,
+    start: usize,
+    end: usize,
+    field: FormatField,
 }
 
 macro_rules! option_len {
@@ -639,6 +910,31 @@ macro_rules! option_len {
 }
 
 impl ParsingResult {
+    fn mark(&mut self, token: usize, start: usize, end: usize, field: FormatField) {
+        self.spans.push(FieldSpan {
+            token,
+            start,
+            end,
+            field,
+        });
+    }
+
+    /// Mark a token as the next value collected by `YMD`
+    fn mark_ymd(&mut self, token: usize, start: usize, end: usize, ymd: &YMD) {
+        self.mark(token, start, end, FormatField::Ymd(ymd.len()));
+    }
+
+    /// Mark `SS[.ss]` starting at byte `start` of a token
+    fn mark_seconds(&mut self, token: usize, start: usize, text: &str) {
+        match text.find('.') {
+            Some(dot) => {
+                self.mark(token, start, start + dot, FormatField::Second);
+                self.mark(token, start + dot + 1, start + text.len(), FormatField::Fraction);
+            }
+            None => self.mark(token, start, start + text.len(), FormatField::Second),
+        }
+    }
+
     fn len(&self) -> usize {
         option_len!(self.year)
             + option_len!(self.month)
@@ -653,12 +949,256 @@ impl ParsingResult {
     }
 }
 
+/// Unit of a numeric timestamp counted from the Unix epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampUnit {
+    /// Seconds since 1970-01-01T00:00:00
+    Seconds,
+    /// Milliseconds since 1970-01-01T00:00:00
+    Milliseconds,
+    /// Microseconds since 1970-01-01T00:00:00
+    Microseconds,
+    /// Nanoseconds since 1970-01-01T00:00:00
+    Nanoseconds,
+}
+
+impl TimestampUnit {
+    fn to_naive(self, negative: bool, int: &str, frac: &str) -> Option<NaiveDateTime> {
+        let nanos = decimal_nanos(negative, int, frac, self.nanos_per_unit())?;
+        offset_nanos(Epoch::Unix.origin(), nanos)
+    }
+
+    fn nanos_per_unit(self) -> i128 {
+        match self {
+            TimestampUnit::Seconds => 1_000_000_000,
+            TimestampUnit::Milliseconds => 1_000_000,
+            TimestampUnit::Microseconds => 1_000,
+            TimestampUnit::Nanoseconds => 1,
+        }
+    }
+}
+
+/// Controls how a string made up of a single number (optionally prefixed
+/// with `@`, as GNU `date` does) is interpreted when epoch parsing is enabled
+/// through `Parser::with_timestamps`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimestampMode {
+    /// Always interpret the number in the given unit
+    Unit(TimestampUnit),
+    /// Interpret the number in the native unit of another epoch, such as
+    /// Windows `FILETIME` ticks
+    Epoch(Epoch),
+    /// Try seconds, milliseconds, microseconds and nanoseconds in that order,
+    /// and use the first unit whose result falls between `earliest` and `latest`
+    /// (inclusive)
+    Detect {
+        /// Earliest timestamp considered plausible
+        earliest: NaiveDateTime,
+        /// Latest timestamp considered plausible
+        latest: NaiveDateTime,
+    },
+}
+
+impl Default for TimestampMode {
+    /// Autodetect the unit, accepting timestamps between 1900 and 2100
+    fn default() -> Self {
+        TimestampMode::Detect {
+            earliest: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+            latest: NaiveDate::from_ymd_opt(2100, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        }
+    }
+}
+
+/// Split a lone numeric timestamp (`@-1412409095.009`) into its sign,
+/// integer digits and fractional digits.
+fn split_timestamp(timestr: &str) -> Option<(bool, &str, &str)> {
+    let s = timestr.trim();
+    let s = s.strip_prefix('@').unwrap_or(s);
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let (int, frac) = match s.find('.') {
+        Some(dot) => (&s[..dot], &s[dot + 1..]),
+        None => (s, ""),
+    };
+
+    if int.is_empty() || !int.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    if !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((negative, int, frac))
+}
+
+/// The order in which the year, month and day of an all-numeric date like
+/// "03/04/05" appear. Each order corresponds to a combination of the `dayfirst`
+/// and `yearfirst` flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateOrder {
+    /// "03/04/2024" is March 4th; neither flag set
+    MonthDayYear,
+    /// "03/04/2024" is April 3rd; `dayfirst` set
+    DayMonthYear,
+    /// "2024/03/04" is March 4th; `yearfirst` set
+    YearMonthDay,
+    /// "2024/03/04" is April 3rd; both flags set
+    YearDayMonth,
+}
+
+impl DateOrder {
+    /// Every order, in the order used to break ties
+    pub const ALL: [DateOrder; 4] = [
+        DateOrder::MonthDayYear,
+        DateOrder::DayMonthYear,
+        DateOrder::YearMonthDay,
+        DateOrder::YearDayMonth,
+    ];
+
+    /// The order selected by the `dayfirst` and `yearfirst` flags
+    pub fn from_flags(dayfirst: bool, yearfirst: bool) -> DateOrder {
+        match (dayfirst, yearfirst) {
+            (false, false) => DateOrder::MonthDayYear,
+            (true, false) => DateOrder::DayMonthYear,
+            (false, true) => DateOrder::YearMonthDay,
+            (true, true) => DateOrder::YearDayMonth,
+        }
+    }
+
+    /// The `(dayfirst, yearfirst)` flags that select this order
+    pub fn flags(self) -> (bool, bool) {
+        match self {
+            DateOrder::MonthDayYear => (false, false),
+            DateOrder::DayMonthYear => (true, false),
+            DateOrder::YearMonthDay => (false, true),
+            DateOrder::YearDayMonth => (true, true),
+        }
+    }
+
+    /// Every order, starting with `preferred` and then in the order of `ALL`
+    fn ranked(preferred: DateOrder) -> Vec<DateOrder> {
+        let mut orders = vec![preferred];
+        orders.extend(DateOrder::ALL.iter().cloned().filter(|&o| o != preferred));
+        orders
+    }
+
+    /// Positions of the year, month and day among `len` numbers read in
+    /// this order. With two numbers there is no year.
+    fn positions(self, len: usize) -> Option<(Option<usize>, usize, usize)> {
+        match (len, self) {
+            (3, DateOrder::MonthDayYear) => Some((Some(2), 0, 1)),
+            (3, DateOrder::DayMonthYear) => Some((Some(2), 1, 0)),
+            (3, DateOrder::YearMonthDay) => Some((Some(0), 1, 2)),
+            (3, DateOrder::YearDayMonth) => Some((Some(0), 2, 1)),
+            (2, DateOrder::MonthDayYear) | (2, DateOrder::YearMonthDay) => Some((None, 0, 1)),
+            (2, DateOrder::DayMonthYear) | (2, DateOrder::YearDayMonth) => Some((None, 1, 0)),
+            _ => None,
+        }
+    }
+}
+
+/// Bounds on the work done for a single string, for parsing untrusted input.
+/// Each limit is unset by default; strings that break one return
+/// `ParseError::LimitExceeded` as soon as it's found, so the length is checked
+/// before anything else and token counts while tokenizing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Limits {
+    /// Longest string accepted, in bytes
+    pub max_bytes: Option<usize>,
+    /// Most tokens a string may split into; "10:49 AM" is 5 tokens, counting
+    /// the space
+    pub max_tokens: Option<usize>,
+    /// Most digits in a single number, counting both sides of a decimal point
+    pub max_digits: Option<usize>,
+    /// Most words fuzzy parsing may skip. Punctuation, whitespace and `jump`
+    /// words aren't counted.
+    pub max_skipped_tokens: Option<usize>,
+}
+
+/// What to do with digits of a second finer than a nanosecond, such as the
+/// last digit of "10:00:00.9999999999"
+///
+/// Fractional hours and minutes, as in "1.2345h" or "10:12.3456", are carried
+/// exactly into the minutes, seconds and nanoseconds, so they are only ever
+/// rounded past the nanosecond, by the same rule. dateutil instead drops any
+/// part of a minute from fractional hours and any part of a second from
+/// fractional minutes, reading "10:12.3456" as 10:12:20 rather than
+/// 10:12:20.736.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FractionRounding {
+    /// Drop them
+    Truncate,
+    /// Round to the nearest nanosecond, and to an even one from halfway
+    HalfEven,
+    /// Round to the nearest nanosecond, and up from halfway
+    HalfUp,
+    /// Return `ParseError::ImpossibleTimestamp` if there are any
+    Reject,
+}
+
+impl Default for FractionRounding {
+    /// Truncate, as earlier versions did
+    fn default() -> Self {
+        FractionRounding::Truncate
+    }
+}
+
+/// The calendar a date was written in, as reported by `Parser::parse_scored`
+/// and `Parser::parse_detailed`. Dates are always returned in the proleptic
+/// Gregorian calendar, converting them if need be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarSystem {
+    /// The Gregorian calendar, used for dates not marked otherwise
+    Gregorian,
+    /// The Julian calendar, for dates marked Old Style or falling before the
+    /// reform date set with `Parser::with_julian_reform`
+    Julian,
+    /// The calendar set with `Parser::with_calendar`
+    Custom,
+}
+
+/// A timestamp from `Parser::parse_scored` or `Parser::parse_detailed`, with
+/// a score for how likely the input really contained it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredParse {
+    /// The parsed timestamp
+    pub datetime: NaiveDateTime,
+    /// Offset from UTC, if one was found and `ignoretz` wasn't set
+    pub offset: Option<FixedOffset>,
+    /// Text skipped over while parsing, as with `fuzzy_with_tokens`
+    pub skipped_tokens: Vec<String>,
+    /// Score from 0 to 1; see `Parser::parse_scored`
+    pub confidence: f64,
+    /// Number of digits written after the seconds' decimal point, before
+    /// they were rounded to nanoseconds; `None` when there was no fraction
+    /// or the string was a timestamp
+    pub fraction_digits: Option<usize>,
+    /// Calendar the date was written in, before it was converted to the
+    /// Gregorian one
+    pub calendar: CalendarSystem,
+}
+
 /// Parser is responsible for doing the actual work of understanding a time string.
 /// The root level `parse` function is responsible for constructing a default `Parser`
 /// and triggering its behavior.
 #[derive(Default)]
 pub struct Parser {
     info: ParserInfo,
+    vocab: Vocabulary,
+    timestamps: Option<TimestampMode>,
+    explicit_order: bool,
+    ambiguous_date_errors: bool,
+    min_confidence: f64,
+    limits: Limits,
+    validate_leap_seconds: bool,
+    fraction_rounding: FractionRounding,
+    year_digits: Option<usize>,
+    julian_reform: Option<NaiveDate>,
+    calendar: Option<Box<dyn Calendar>>,
 }
 
 impl Parser {
@@ -668,7 +1208,128 @@ impl Parser {
     /// names for days of the week, months, etc., enabling customization
     /// for different languages or extra values.
     pub fn new(info: ParserInfo) -> Self {
-        Parser { info }
+        Parser {
+            vocab: Vocabulary::compile(&info),
+            info,
+            timestamps: None,
+            explicit_order: false,
+            ambiguous_date_errors: false,
+            min_confidence: 0.0,
+            limits: Limits::default(),
+            validate_leap_seconds: false,
+            fraction_rounding: FractionRounding::default(),
+            year_digits: None,
+            julian_reform: None,
+            calendar: None,
+        }
+    }
+
+    /// Enable interpretation of a lone integer or decimal number, such as
+    /// "1412409095009" or "@-86400.5", as a timestamp relative to the Unix epoch,
+    /// or to one of the epochs in the `epoch` module.
+    ///
+    /// Timestamps are always in UTC; the returned offset is UTC unless `ignoretz`
+    /// is set. Because every lone number is treated as a timestamp, strings like
+    /// "2003" or "20030925" will no longer be read as calendar dates.
+    pub fn with_timestamps(mut self, mode: TimestampMode) -> Self {
+        self.timestamps = Some(mode);
+        self
+    }
+
+    /// Read all-numeric dates in `order` unless `dayfirst` or `yearfirst` are
+    /// passed explicitly, replacing the defaults from `ParserInfo`.
+    pub fn with_date_order(mut self, order: DateOrder) -> Self {
+        let (dayfirst, yearfirst) = order.flags();
+        self.info.dayfirst = dayfirst;
+        self.info.yearfirst = yearfirst;
+        self.explicit_order = true;
+        self
+    }
+
+    /// Return `ParseError::AmbiguousDate` rather than guessing when an
+    /// all-numeric date like "01/02/03" gives different dates in different
+    /// orders. ISO 8601 style dates like "2024-03-05" are always read
+    /// year-month-day. Strings are still parsed normally when `dayfirst` or
+    /// `yearfirst` is passed to `parse`, or an order was set with
+    /// `with_date_order`.
+    pub fn with_ambiguous_date_errors(mut self, enabled: bool) -> Self {
+        self.ambiguous_date_errors = enabled;
+        self
+    }
+
+    /// Return `ParseError::LowConfidence` from fuzzy parses whose confidence,
+    /// as reported by `parse_scored`, is below `threshold`. Parses that aren't
+    /// fuzzy are never rejected, since they can't skip any text.
+    pub fn with_min_confidence(mut self, threshold: f64) -> Self {
+        self.min_confidence = threshold;
+        self
+    }
+
+    /// Return `ParseError::LimitExceeded` for strings that break any of
+    /// `limits`, before doing the work they'd need.
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Return `ParseError::ImpossibleTimestamp` for a second of 60 unless it
+    /// is one of the leap seconds inserted into UTC since 1972. Times without
+    /// an offset are taken to be UTC. Without this, ":60" is accepted in any
+    /// minute, as chrono does.
+    pub fn with_leap_second_validation(mut self, enabled: bool) -> Self {
+        self.validate_leap_seconds = enabled;
+        self
+    }
+
+    /// Treat digits of a second past the ninth as `rounding` says, rather
+    /// than truncating them. Rounding up can carry into the seconds, minutes
+    /// and date, so "1999-12-31 23:59:59.9999999999" rounds to midnight.
+    pub fn with_fraction_rounding(mut self, rounding: FractionRounding) -> Self {
+        self.fraction_rounding = rounding;
+        self
+    }
+
+    /// Read numbers of exactly `digits` digits as years, the way ISO 8601
+    /// expanded representations agree on a year length, so with 6 digits
+    /// "012345-01-01" is in the year 12345 rather than a YYMMDD date.
+    ///
+    /// Signed years starting a date, like "+002024-03-05" or
+    /// "-000044-03-15", are always read, using astronomical numbering; this
+    /// also requires them to have exactly `digits` digits rather than at
+    /// least 4. Years chrono can't represent give
+    /// `ParseError::ImpossibleTimestamp`.
+    pub fn with_year_digits(mut self, digits: usize) -> Self {
+        self.year_digits = Some(digits);
+        self
+    }
+
+    /// Read dates before `first_gregorian`, the first day a country used the
+    /// Gregorian calendar, in the Julian calendar, converting them to the
+    /// Gregorian one. That was 1582-10-15 in much of Catholic Europe and
+    /// 1752-09-14 in Britain and its colonies.
+    ///
+    /// Dates marked Old Style or New Style, as set in
+    /// `ParserInfo::calendar_style`, are read in the calendar they're marked
+    /// with whether or not this is set.
+    pub fn with_julian_reform(mut self, first_gregorian: NaiveDate) -> Self {
+        self.julian_reform = Some(first_gregorian);
+        self
+    }
+
+    /// Read dates in `calendar` rather than the Gregorian one, recognizing
+    /// its month names in place of those in `ParserInfo::months`, so with
+    /// `calendar::Persian` "1402/12/15" is 2024-03-05. Missing fields are
+    /// filled in from the default date in that calendar, days past the end
+    /// of a month are moved back to its last day, and two-digit years are
+    /// completed from the current year in that calendar.
+    ///
+    /// Dates are always returned in the Gregorian calendar, so Julian
+    /// dates, eras and offset years aren't converted too.
+    pub fn with_calendar<C: Calendar + 'static>(mut self, calendar: C) -> Self {
+        self.info.months = parse_info(calendar.month_names());
+        self.vocab = Vocabulary::compile(&self.info);
+        self.calendar = Some(Box::new(calendar));
+        self
     }
 
     /// Main method to trigger parsing of a string using the previously-provided
@@ -713,28 +1374,307 @@ impl Parser {
         ignoretz: bool,
         tzinfos: &HashMap<String, i32>,
     ) -> ParseResult<(NaiveDateTime, Option<FixedOffset>, Option<Vec<String>>)> {
+        self.check_length(timestr)?;
         let default_date = default.unwrap_or(&Local::now().naive_local()).date();
 
         let default_ts = NaiveDateTime::new(default_date, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
 
+        if let Some(naive) = self.parse_timestamp(timestr) {
+            let offset = if ignoretz {
+                None
+            } else {
+                FixedOffset::east_opt(0)
+            };
+            let tokens = if fuzzy_with_tokens { Some(vec![]) } else { None };
+            return Ok((naive?, offset, tokens));
+        }
+
+        // Fuzzy parsing and ambiguity errors depend on more than the result,
+        // `dayfirst` reads "2024-03-05" as May 3rd, early dates may be Julian
+        // and years may be in an offset calendar. Canonical strings have up
+        // to 15 tokens, and 11 digits in "00.123456789".
+        let dayfirst_set = dayfirst.unwrap_or(self.info.dayfirst);
+        let within_limits = self.limits.max_tokens.is_none_or(|max| max >= 15)
+            && self.limits.max_digits.is_none_or(|max| max >= 11);
+        let gregorian = self.julian_reform.is_none()
+            && self.calendar.is_none()
+            && self.info.offset_calendar.as_ref().is_none_or(|calendar| !calendar.unmarked);
+        if within_limits
+            && gregorian
+            && !(fuzzy || fuzzy_with_tokens || self.ambiguous_date_errors || dayfirst_set)
+        {
+            if let Some((naive, offset)) = iso::parse(timestr) {
+                let offset = offset.filter(|_| !ignoretz).and_then(FixedOffset::east_opt);
+                return Ok((naive, offset, None));
+            }
+        }
+
         let (res, tokens) =
             self.parse_with_tokens(timestr, dayfirst, yearfirst, fuzzy, fuzzy_with_tokens)?;
+        let (naive, offset) = self.build_result(&res, &default_ts, ignoretz, tzinfos)?;
 
+        Ok((naive, offset, tokens))
+    }
+
+    /// Parse `timestr` in fuzzy mode, scoring from 0 to 1 how likely it is
+    /// that the text really contained the timestamp found. In fuzzy mode
+    /// "Room 12, floor 5" is December 5th, which scores 0.4, while
+    /// "Sep 25 2003 10:49" scores 1. The score adds up:
+    ///
+    /// * 0.4 times the share of words that weren't skipped, not counting
+    ///   punctuation and `jump` words
+    /// * 0.15 if a month name was used
+    /// * 0.15 if there was a four-digit year
+    /// * 0.1 if the date used at most one kind of separator, so "25/09-2003"
+    ///   scores lower than "25/09/2003"
+    /// * 0.05 for each of the year, month, day and time of day that was in the
+    ///   string rather than taken from `default`
+    ///
+    /// Timestamps read with `with_timestamps` always score 1. The other
+    /// arguments behave as in `parse`.
+    pub fn parse_scored(
+        &self,
+        timestr: &str,
+        dayfirst: Option<bool>,
+        yearfirst: Option<bool>,
+        default: Option<&NaiveDateTime>,
+        ignoretz: bool,
+        tzinfos: &HashMap<String, i32>,
+    ) -> ParseResult<ScoredParse> {
+        self.parse_details(timestr, dayfirst, yearfirst, true, default, ignoretz, tzinfos)
+    }
+
+    /// Parse `timestr` without fuzzy mode, as `parse` does, but return the
+    /// same details as `parse_scored`: how many fraction digits were written
+    /// and which calendar the date was in. The score is worked out as for
+    /// `parse_scored`, but `with_min_confidence` doesn't apply, and nothing
+    /// is ever skipped.
+    pub fn parse_detailed(
+        &self,
+        timestr: &str,
+        dayfirst: Option<bool>,
+        yearfirst: Option<bool>,
+        default: Option<&NaiveDateTime>,
+        ignoretz: bool,
+        tzinfos: &HashMap<String, i32>,
+    ) -> ParseResult<ScoredParse> {
+        self.parse_details(timestr, dayfirst, yearfirst, false, default, ignoretz, tzinfos)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn parse_details(
+        &self,
+        timestr: &str,
+        dayfirst: Option<bool>,
+        yearfirst: Option<bool>,
+        fuzzy: bool,
+        default: Option<&NaiveDateTime>,
+        ignoretz: bool,
+        tzinfos: &HashMap<String, i32>,
+    ) -> ParseResult<ScoredParse> {
+        self.check_length(timestr)?;
+        let default_date = default.unwrap_or(&Local::now().naive_local()).date();
+        let default_ts = NaiveDateTime::new(default_date, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+
+        if let Some(naive) = self.parse_timestamp(timestr) {
+            return Ok(ScoredParse {
+                datetime: naive?,
+                offset: if ignoretz { None } else { FixedOffset::east_opt(0) },
+                skipped_tokens: Vec::new(),
+                confidence: 1.0,
+                fraction_digits: None,
+                calendar: CalendarSystem::Gregorian,
+            });
+        }
+
+        let (res, tokens) = self.parse_with_tokens(timestr, dayfirst, yearfirst, fuzzy, fuzzy)?;
+        let (datetime, offset) = self.build_result(&res, &default_ts, ignoretz, tzinfos)?;
+
+        Ok(ScoredParse {
+            datetime,
+            offset,
+            skipped_tokens: tokens.unwrap_or_default(),
+            confidence: res.confidence,
+            fraction_digits: res.fraction_digits,
+            calendar: self.calendar_system(&res, &default_ts),
+        })
+    }
+
+    /// Parse `timestr` in every date order that gives a different, valid date,
+    /// rather than picking one using `dayfirst` and `yearfirst`. "01/02/03"
+    /// gives four candidates: January 2nd and February 1st 2003, and February
+    /// 3rd and March 2nd 2001. Each is labelled with the order it assumes.
+    ///
+    /// Candidates are listed starting with the order set in the `ParserInfo`.
+    /// Strings whose date doesn't depend on the order, like "March 4 2024",
+    /// give a single candidate labelled with that order. The other arguments
+    /// behave as in `parse`.
+    pub fn parse_candidates(
+        &self,
+        timestr: &str,
+        fuzzy: bool,
+        default: Option<&NaiveDateTime>,
+        ignoretz: bool,
+        tzinfos: &HashMap<String, i32>,
+    ) -> ParseResult<Vec<(DateOrder, NaiveDateTime, Option<FixedOffset>)>> {
+        self.check_length(timestr)?;
+        let default_date = default.unwrap_or(&Local::now().naive_local()).date();
+        let default_ts = NaiveDateTime::new(default_date, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+        let preferred = DateOrder::from_flags(self.info.dayfirst, self.info.yearfirst);
+
+        if let Some(naive) = self.parse_timestamp(timestr) {
+            let offset = if ignoretz {
+                None
+            } else {
+                FixedOffset::east_opt(0)
+            };
+            return Ok(vec![(preferred, naive?, offset)]);
+        }
+
+        let mut scanned = self.scan_tokens(timestr, fuzzy)?;
+        let readings = scanned.ymd.readings(preferred, self.calendar.as_deref());
+
+        if readings.is_empty() {
+            let (dayfirst, yearfirst) = preferred.flags();
+            let resolved = scanned.ymd.resolve_ymd(yearfirst, dayfirst)?;
+            let (res, _) = self.assign_ymd(scanned, resolved, false)?;
+            let (naive, offset) = self.build_result(&res, &default_ts, ignoretz, tzinfos)?;
+            return Ok(vec![(preferred, naive, offset)]);
+        }
+
+        let mut candidates = Vec::new();
+        let mut first_error = None;
+        for (order, resolved) in readings {
+            let result = self
+                .assign_ymd(scanned.clone(), resolved, false)
+                .and_then(|(res, _)| self.build_result(&res, &default_ts, ignoretz, tzinfos));
+            match result {
+                Ok((naive, offset)) => candidates.push((order, naive, offset)),
+                Err(e) => first_error = first_error.or(Some(e)),
+            }
+        }
+
+        match first_error {
+            Some(e) if candidates.is_empty() => Err(e),
+            _ => Ok(candidates),
+        }
+    }
+
+    /// Work out a strftime-compatible pattern, such as `%d/%m/%Y %H:%M`, that
+    /// describes `timestr` based on how the parser understood each token.
+    /// Formatting the parsed timestamp with the pattern using chrono reproduces
+    /// `timestr` exactly; if no such pattern exists (for example, "10.5h" or
+    /// "SEP 2003"), `ParseError::NoFormatPattern` is returned.
+    ///
+    /// Ambiguous dates are resolved using the `dayfirst` and `yearfirst`
+    /// settings of the `ParserInfo`, and epoch timestamps aren't considered.
+    pub fn infer_format(&self, timestr: &str) -> ParseResult<String> {
+        let (res, _) = self.parse_with_tokens(timestr, None, None, false, false)?;
         if res.len() == 0 {
             return Err(ParseError::NoDate);
         }
 
-        let naive = self.build_naive(&res, &default_ts)?;
+        let default = Local::now().naive_local().date().and_hms_opt(0, 0, 0).unwrap();
+        let naive = self.build_naive(&res, &default)?;
+
+        let twelve_hour = res.spans.iter().any(|span| span.field == FormatField::AmPm);
+        let mut pattern = String::new();
+
+        for (i, token) in Tokenizer::new(timestr).enumerate() {
+            let token = token.text;
+            let mut spans: Vec<&FieldSpan> = res.spans.iter().filter(|span| span.token == i).collect();
+            spans.sort_by_key(|span| span.start);
+
+            let mut pos = 0;
+            for span in spans {
+                pattern.push_str(&token[pos..span.start].replace('%', "%%"));
+                pattern.push_str(span.field.specifier(&token[span.start..span.end], twelve_hour)?);
+                pos = span.end;
+            }
+            pattern.push_str(&token[pos..].replace('%', "%%"));
+        }
+
+        let mut formatted = String::new();
+        let written = match res.tzoffset.and_then(FixedOffset::east_opt) {
+            Some(offset) => match offset.from_local_datetime(&naive).single() {
+                Some(dt) => write!(formatted, "{}", dt.format(&pattern)),
+                None => Err(fmt::Error),
+            },
+            None => write!(formatted, "{}", naive.format(&pattern)),
+        };
 
-        if !ignoretz {
-            let offset = self.build_tzaware(&naive, &res, tzinfos)?;
-            Ok((naive, offset, tokens))
+        if written.is_ok() && formatted == timestr {
+            Ok(pattern)
         } else {
-            Ok((naive, None, tokens))
+            Err(ParseError::NoFormatPattern("Pattern doesn't reproduce the input"))
         }
     }
 
-    #[allow(clippy::cognitive_complexity)] // Imitating Python API is priority
+    fn check_length(&self, timestr: &str) -> ParseResult<()> {
+        if self.limits.max_bytes.is_some_and(|max| timestr.len() > max) {
+            Err(ParseError::LimitExceeded("Input too long"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_skipped(&self, skipped_words: usize) -> ParseResult<()> {
+        if self.limits.max_skipped_tokens.is_some_and(|max| skipped_words > max) {
+            Err(ParseError::LimitExceeded("Too many skipped tokens"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Split `timestr` into tokens, stopping at the first one that breaks
+    /// the `Limits`
+    fn tokenize_within_limits<'a>(&self, timestr: &'a str) -> ParseResult<Vec<Cow<'a, str>>> {
+        self.check_length(timestr)?;
+
+        let mut tokens = Vec::new();
+        for token in Tokenizer::new(timestr) {
+            if self.limits.max_tokens.is_some_and(|max| tokens.len() >= max) {
+                return Err(ParseError::LimitExceeded("Too many tokens"));
+            }
+            if let Some(max) = self.limits.max_digits {
+                if token.text.chars().filter(|c| c.is_numeric()).count() > max {
+                    return Err(ParseError::LimitExceeded("Too many digits in a number"));
+                }
+            }
+            tokens.push(token.value());
+        }
+        Ok(tokens)
+    }
+
+    /// Returns `None` if timestamps aren't enabled or the string isn't a lone number
+    fn parse_timestamp(&self, timestr: &str) -> Option<ParseResult<NaiveDateTime>> {
+        let mode = self.timestamps?;
+        let (negative, int, frac) = split_timestamp(timestr)?;
+        if self.limits.max_digits.is_some_and(|max| int.len() + frac.len() > max) {
+            return Some(Err(ParseError::LimitExceeded("Too many digits in a number")));
+        }
+        let out_of_range = || ParseError::ImpossibleTimestamp("Timestamp out of range");
+
+        let naive = match mode {
+            TimestampMode::Unit(unit) => unit.to_naive(negative, int, frac).ok_or_else(out_of_range),
+            TimestampMode::Epoch(epoch) => epoch
+                .decimal_to_naive(negative, int, frac)
+                .ok_or_else(out_of_range),
+            TimestampMode::Detect { earliest, latest } => [
+                TimestampUnit::Seconds,
+                TimestampUnit::Milliseconds,
+                TimestampUnit::Microseconds,
+                TimestampUnit::Nanoseconds,
+            ]
+            .iter()
+            .filter_map(|unit| unit.to_naive(negative, int, frac))
+            .find(|naive| earliest <= *naive && *naive <= latest)
+            .ok_or_else(out_of_range),
+        };
+
+        Some(naive)
+    }
+
     fn parse_with_tokens(
         &self,
         timestr: &str,
@@ -744,6 +1684,7 @@ impl Parser {
         fuzzy_with_tokens: bool,
     ) -> Result<(ParsingResult, Option<Vec<String>>), ParseError> {
         let fuzzy = if fuzzy_with_tokens { true } else { fuzzy };
+        let explicit_order = dayfirst.is_some() || yearfirst.is_some() || self.explicit_order;
         // This is probably a stylistic abomination
         let dayfirst = if let Some(dayfirst) = dayfirst {
             dayfirst
@@ -756,10 +1697,195 @@ impl Parser {
             self.info.yearfirst
         };
 
+        let mut scanned = self.scan_tokens(timestr, fuzzy)?;
+
+        let resolved = self.resolve_ymd(&mut scanned.ymd, yearfirst, dayfirst, explicit_order)?;
+        let confidence = self.confidence(&scanned, resolved);
+        if fuzzy && confidence < self.min_confidence {
+            return Err(ParseError::LowConfidence(confidence));
+        }
+
+        let (mut res, tokens) = self.assign_ymd(scanned, resolved, fuzzy_with_tokens)?;
+        res.confidence = confidence;
+        Ok((res, tokens))
+    }
+
+    /// Decide which of the values in `ymd` are the year, month and day, or
+    /// return `ParseError::AmbiguousDate` if that was asked for and the
+    /// order isn't `explicit_order` but matters
+    fn resolve_ymd(
+        &self,
+        ymd: &mut YMD,
+        yearfirst: bool,
+        dayfirst: bool,
+        explicit_order: bool,
+    ) -> ParseResult<YmdPositions> {
+        if self.ambiguous_date_errors && !explicit_order {
+            let order = DateOrder::from_flags(dayfirst, yearfirst);
+            let mut readings = ymd.readings(order, self.calendar.as_deref());
+            if readings.len() > 1 {
+                let orders = readings.into_iter().map(|(order, _)| order).collect();
+                return Err(ParseError::AmbiguousDate(orders));
+            }
+            // With only one valid reading there's nothing to guess, even if
+            // `dayfirst` would read "2024-03-05" as May 3rd
+            if let Some((_, positions)) = readings.pop() {
+                return Ok(positions);
+            }
+        }
+
+        ymd.resolve_ymd(yearfirst, dayfirst)
+    }
+
+    /// Score how likely it is that a scanned string really contains the
+    /// timestamp found in it; see `parse_scored` for how.
+    fn confidence(&self, scanned: &Scanned, (year, month, day): YmdPositions) -> f64 {
+        let tokens = &scanned.tokens;
+        let is_word =
+            |token: &Cow<str>| token.chars().any(char::is_alphanumeric) && !self.vocab.lookup(token).jump;
+
+        let words = tokens.iter().filter(|&t| is_word(t)).count();
+        let skipped = scanned.skipped_idxs.iter().filter(|&&i| is_word(&tokens[i])).count();
+        let coverage = if words == 0 {
+            1.0
+        } else {
+            (words - skipped) as f64 / words as f64
+        };
+
+        let month_name = tokens.iter().any(|t| self.vocab.lookup(t).month.is_some());
+        let four_digit_year = year
+            .map(|y| scanned.ymd._ymd[y])
+            .is_some_and(|y| scanned.ymd.century_specified && (1000..=9999).contains(&y));
+
+        let date_tokens = scanned.res.spans.iter().filter_map(|span| match span.field {
+            FormatField::Ymd(_) => Some(span.token),
+            _ => None,
+        });
+        let first = date_tokens.clone().min().unwrap_or(0);
+        let last = date_tokens.max().unwrap_or(0);
+        let mut separators: Vec<&str> = tokens
+            .iter()
+            .take(last)
+            .skip(first)
+            .map(|t| t.trim())
+            .filter(|t| !t.is_empty() && !t.chars().any(char::is_alphanumeric))
+            .collect();
+        separators.dedup();
+
+        let given = [year, month, day, scanned.res.hour.map(|_| 0)]
+            .iter()
+            .filter(|field| field.is_some())
+            .count();
+
+        let mut score = 0.4 * coverage + 0.05 * given as f64;
+        if month_name {
+            score += 0.15;
+        }
+        if four_digit_year {
+            score += 0.15;
+        }
+        if separators.len() <= 1 {
+            score += 0.1;
+        }
+        score.min(1.0)
+    }
+
+    /// Fill in the year, month and day of a scanned string from the positions
+    /// chosen for them, then validate the result
+    fn assign_ymd(
+        &self,
+        scanned: Scanned,
+        (year, month, day): YmdPositions,
+        fuzzy_with_tokens: bool,
+    ) -> Result<(ParsingResult, Option<Vec<String>>), ParseError> {
+        let Scanned {
+            mut res,
+            ymd,
+            tokens: l,
+            skipped_idxs,
+        } = scanned;
+
+        for span in res.spans.iter_mut() {
+            if let FormatField::Ymd(idx) = span.field {
+                span.field = if year == Some(idx) {
+                    FormatField::Year
+                } else if month == Some(idx) {
+                    FormatField::Month
+                } else if day == Some(idx) {
+                    FormatField::Day
+                } else {
+                    FormatField::Unsupported
+                };
+            }
+        }
+
+        res.century_specified = ymd.century_specified;
+        res.year = year.map(|i| ymd._ymd[i]);
+        res.month = month.map(|i| ymd._ymd[i]);
+        res.day = day.map(|i| ymd._ymd[i]);
+        self.adjust_year(&mut res)?;
+
+        if !self.info.validate(&mut res, &self.vocab) {
+            Err(ParseError::UnrecognizedFormat)
+        } else if fuzzy_with_tokens {
+            let skipped_tokens = self.recombine_skipped(skipped_idxs, l);
+            Ok((res, Some(skipped_tokens)))
+        } else {
+            Ok((res, None))
+        }
+    }
+
+    /// Move the year in `res` onto the proleptic Gregorian year numbering for
+    /// eras and offset calendars, or complete a two-digit year in the
+    /// calendar set with `with_calendar`
+    fn adjust_year(&self, res: &mut ParsingResult) -> ParseResult<()> {
+        if let Some(before) = res.era {
+            // Eras count from 1, so 1 BC is year 0 and 44 BC is year -43
+            res.year = match res.year {
+                Some(year) if year < 1 => return Err(ParseError::ImpossibleTimestamp("Invalid year")),
+                Some(year) if before => Some(1 - year),
+                None if before => return Err(ParseError::ImpossibleTimestamp("Era without a year")),
+                year => year,
+            };
+        }
+
+        if let Some(ref calendar) = self.info.offset_calendar {
+            if res.offset_year || (calendar.unmarked && res.century_specified) {
+                res.year = match res.year {
+                    Some(year) => Some(
+                        year.checked_add(calendar.offset)
+                            .ok_or(ParseError::ImpossibleTimestamp("Year out of range"))?,
+                    ),
+                    None if res.offset_year => {
+                        return Err(ParseError::ImpossibleTimestamp("Era without a year"))
+                    }
+                    None => None,
+                };
+            }
+        }
+
+        if let (Some(calendar), Some(year)) = (self.calendar.as_ref(), res.year) {
+            if year < 100 && !res.century_specified {
+                let current = NaiveDate::from_ymd_opt(self.info.year, 7, 1)
+                    .map_or(self.info.year, |today| calendar.ymd(today).0);
+                res.year = Some(complete_year(year, current, current / 100 * 100));
+                res.century_specified = true;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Walk the tokens of `timestr`, filling in everything but the year, month
+    /// and day, which are collected for `YMD::resolve_ymd` to tell apart.
+    /// Nothing here depends on `dayfirst` or `yearfirst`.
+    #[allow(clippy::cognitive_complexity)] // Imitating Python API is priority
+    fn scan_tokens<'a>(&self, timestr: &'a str, fuzzy: bool) -> ParseResult<Scanned<'a>> {
         let mut res = ParsingResult::default();
 
-        let mut l = tokenize(&timestr);
+        let mut l = self.tokenize_within_limits(timestr)?;
         let mut skipped_idxs: Vec<usize> = Vec::new();
+        let mut skipped_words = 0;
 
         let mut ymd = YMD::default();
 
@@ -768,23 +1894,73 @@ impl Parser {
 
         while i < len_l {
             let value_repr = l[i].clone();
-
-            if let Ok(_v) = Decimal::from_str(&value_repr) {
-                i = self.parse_numeric_token(&l, i, &self.info, &mut ymd, &mut res, fuzzy)?;
-            } else if let Some(value) = self.info.weekday_index(&l[i]) {
+            let word = *self.vocab.lookup(&value_repr);
+
+            if let Some(value) = Number::parse(&value_repr) {
+                i = self.parse_numeric_token(&l, i, value, &self.vocab, &mut ymd, &mut res, fuzzy)?;
+            } else if let Some(idx) = self.signed_year(&l, i, &ymd, &res) {
+                // +002024-03-05
+                res.mark(i, 0, 1, FormatField::Unsupported);
+                res.mark(idx, 0, l[idx].len(), FormatField::Unsupported);
+                ymd.append(expanded_year(&l[idx], l[i] == "-")?, &l[idx], Some(YMDLabel::Year))?;
+                i = idx;
+            } else if let Some((end, marker)) = self.vocab.marker(&l, i) {
+                for (tok, token) in l.iter().enumerate().take(end + 1).skip(i) {
+                    res.mark(tok, 0, token.len(), FormatField::Unsupported);
+                }
+                i = match marker {
+                    // 44 BC, A.D. 1066
+                    Marker::Era(before) => {
+                        res.era = Some(before);
+                        self.assign_marked_year(&l, i, end, &mut ymd, &mut res)?
+                    }
+                    // BE 2567, 民國113年
+                    Marker::YearOffset => {
+                        res.offset_year = true;
+                        self.assign_marked_year(&l, i, end, &mut ymd, &mut res)?
+                    }
+                    // 24 Adar II 5784
+                    Marker::Month(value) => {
+                        ymd.append(value as i32, &l[i], Some(YMDLabel::Month))?;
+                        end
+                    }
+                    // 5 March 1700 O.S.
+                    Marker::Style(julian) => {
+                        res.julian = Some(julian);
+                        end
+                    }
+                };
+            } else if let Some((era, end, year)) = self.regnal_year(&l, i, &ymd, &res) {
+                // 令和6年, R6.3.5, 令和元年
+                for (tok, token) in l.iter().enumerate().take(end + 1).skip(i) {
+                    res.mark(tok, 0, token.len(), FormatField::Unsupported);
+                }
+                let year = self.info.regnal_eras[era]
+                    .start
+                    .year()
+                    .checked_add(year - 1)
+                    .ok_or(ParseError::ImpossibleTimestamp("Year out of range"))?;
+                ymd.append(year, &l[end], Some(YMDLabel::Year))?;
+                ymd.century_specified = true;
+                res.regnal_era = Some(era);
+                i = end;
+            } else if let Some(value) = word.weekday {
                 res.weekday = Some(value);
-            } else if let Some(value) = self.info.month_index(&l[i]) {
+                res.mark(i, 0, l[i].len(), FormatField::Weekday);
+            } else if let Some(value) = word.month {
+                res.mark_ymd(i, 0, l[i].len(), &ymd);
                 ymd.append(value as i32, &l[i], Some(YMDLabel::Month))?;
 
                 if i + 1 < len_l {
-                    if l[i + 1] == "-" || l[i + 1] == "/" {
+                    if i + 2 < len_l && (l[i + 1] == "-" || l[i + 1] == "/") {
                         // Jan-01[-99]
                         let sep = &l[i + 1];
-                        // TODO: This seems like a very unsafe unwrap
+                        res.mark_ymd(i + 2, 0, l[i + 2].len(), &ymd);
                         ymd.append(l[i + 2].parse::<i32>()?, &l[i + 2], None)?;
 
-                        if i + 3 < len_l && &l[i + 3] == sep {
+                        if i + 4 < len_l && &l[i + 3] == sep {
                             // Jan-01-99
+                            res.mark_ymd(i + 4, 0, l[i + 4].len(), &ymd);
                             ymd.append(l[i + 4].parse::<i32>()?, &l[i + 4], None)?;
                             i += 2;
                         }
@@ -793,31 +1969,38 @@ impl Parser {
                     } else if i + 4 < len_l
                         && l[i + 1] == l[i + 3]
                         && l[i + 3] == " "
-                        && self.info.pertain_index(&l[i + 2])
+                        && self.vocab.lookup(&l[i + 2]).pertain
                     {
                         // Jan of 01
                         if let Ok(value) = l[i + 4].parse::<i32>() {
                             let year = self.info.convertyear(value, false);
+                            res.mark_ymd(i + 4, 0, l[i + 4].len(), &ymd);
                             ymd.append(year, &l[i + 4], Some(YMDLabel::Year))?;
                         }
 
                         i += 4;
                     }
                 }
-            } else if let Some(value) = self.info.ampm_index(&l[i]) {
+            } else if let Some(idx) = self.julian_day_number(&l, i) {
+                // JD 2460374.5
+                self.assign_julian_day(&l, i, idx, &mut ymd, &mut res)?;
+                res.mark(i, 0, l[i].len(), FormatField::Unsupported);
+                i = idx;
+            } else if let Some(value) = word.ampm {
                 let is_ampm = self.ampm_valid(res.hour, res.ampm, fuzzy);
 
                 if is_ampm == Ok(true) {
                     res.hour = res.hour.map(|h| self.adjust_ampm(h, value));
                     res.ampm = Some(value);
+                    res.mark(i, 0, l[i].len(), FormatField::AmPm);
                 } else if fuzzy {
+                    skipped_words += 1;
+                    self.check_skipped(skipped_words)?;
                     skipped_idxs.push(i);
                 }
             } else if self.could_be_tzname(res.hour, &res.tzname, res.tzoffset, &l[i]) {
-                res.tzname = Some(l[i].clone());
-
-                let tzname = res.tzname.clone().unwrap();
-                res.tzoffset = self.info.tzoffset_index(&tzname).map(|t| t as i32);
+                res.tzname = Some(l[i].to_string());
+                res.tzoffset = word.tzoffset.map(|t| t as i32);
 
                 if i + 1 < len_l && (l[i + 1] == "+" || l[i + 1] == "-") {
                     // GMT+3
@@ -825,84 +2008,263 @@ impl Parser {
                     // "my time +3 is GMT" not "GMT +3 is my time"
 
                     // TODO: Is there a better way of in-place modifying a vector?
-                    let item = if l[i + 1] == "+" {
-                        "-".to_owned()
-                    } else {
-                        "+".to_owned()
-                    };
-                    l[i + 1] = item;
+                    let item = if l[i + 1] == "+" { "-" } else { "+" };
+                    l[i + 1] = Cow::Borrowed(item);
 
                     res.tzoffset = None;
 
-                    if self.info.utczone_index(&tzname) {
+                    if word.utczone {
                         res.tzname = None;
                     }
                 }
             } else if res.hour.is_some() && (l[i] == "+" || l[i] == "-") {
                 let signal = if l[i] == "+" { 1 } else { -1 };
-                let len_li = l[i].len();
-
-                let mut hour_offset: Option<i32> = None;
-                let mut min_offset: Option<i32> = None;
+                let len_li = l.get(i + 1).map_or(0, |t| t.len());
 
                 // TODO: check that l[i + 1] is integer?
-                if len_li == 4 {
+                let (hour_offset, min_offset) = if len_li == 4 && l[i + 1].is_ascii() {
                     // -0300
-                    hour_offset = Some(l[i + 1][..2].parse::<i32>()?);
-                    min_offset = Some(l[i + 1][2..4].parse::<i32>()?);
-                } else if i + 2 < len_l && l[i + 2] == ":" {
+                    res.mark(i, 0, 1, FormatField::Offset(false));
+                    res.mark(i + 1, 0, 4, FormatField::Consumed);
+                    (l[i + 1][..2].parse::<i32>()?, l[i + 1][2..4].parse::<i32>()?)
+                } else if i + 3 < len_l && l[i + 2] == ":" {
                     // -03:00
-                    hour_offset = Some(l[i + 1].parse::<i32>()?);
-                    min_offset = Some(l[i + 3].parse::<i32>()?);
+                    res.mark(i, 0, 1, FormatField::Offset(true));
+                    for (tok, token) in l.iter().enumerate().take(min(i + 4, len_l)).skip(i + 1) {
+                        res.mark(tok, 0, token.len(), FormatField::Consumed);
+                    }
+                    let offset = (l[i + 1].parse::<i32>()?, l[i + 3].parse::<i32>()?);
                     i += 2;
-                } else if len_li <= 2 {
+                    offset
+                } else if (1..=2).contains(&len_li) {
                     // -[0]3
-                    let range_len = min(l[i + 1].len(), 2);
-                    hour_offset = Some(l[i + 1][..range_len].parse::<i32>()?);
-                    min_offset = Some(0);
-                }
+                    res.mark(i, 0, 1, FormatField::Unsupported);
+                    (l[i + 1].parse::<i32>()?, 0)
+                } else {
+                    return Err(ParseError::UnrecognizedFormat);
+                };
 
-                res.tzoffset =
-                    Some(signal * (hour_offset.unwrap() * 3600 + min_offset.unwrap() * 60));
+                let offset = hour_offset
+                    .checked_mul(3600)
+                    .and_then(|hours| hours.checked_add(min_offset.checked_mul(60)?))
+                    .ok_or(ParseError::ImpossibleTimestamp("Invalid offset"))?;
+                res.tzoffset = Some(signal * offset);
 
                 let tzname = res.tzname.clone();
                 if i + 5 < len_l
-                    && self.info.jump_index(&l[i + 2])
+                    && self.vocab.lookup(&l[i + 2]).jump
                     && l[i + 3] == "("
                     && l[i + 5] == ")"
                     && 3 <= l[i + 4].len()
                     && self.could_be_tzname(res.hour, &tzname, None, &l[i + 4])
                 {
                     // (GMT)
-                    res.tzname = Some(l[i + 4].clone());
+                    res.tzname = Some(l[i + 4].to_string());
                     i += 4;
                 }
 
                 i += 1;
-            } else if !(self.info.jump_index(&l[i]) || fuzzy) {
-                return Err(ParseError::UnrecognizedToken(l[i].clone()));
+            } else if !(word.jump || fuzzy) {
+                return Err(ParseError::UnrecognizedToken(l[i].to_string()));
             } else {
+                if !word.jump {
+                    skipped_words += 1;
+                    self.check_skipped(skipped_words)?;
+                }
                 skipped_idxs.push(i);
             }
 
             i += 1;
         }
 
-        let (year, month, day) = ymd.resolve_ymd(yearfirst, dayfirst)?;
+        Ok(Scanned {
+            res,
+            ymd,
+            tokens: l,
+            skipped_idxs,
+        })
+    }
 
-        res.century_specified = ymd.century_specified;
-        res.year = year;
-        res.month = month;
-        res.day = day;
+    /// If `tokens[idx]` introduces a Julian Day number, find the index of the number
+    fn julian_day_number(&self, tokens: &[Cow<str>], idx: usize) -> Option<usize> {
+        self.vocab.lookup(&tokens[idx]).julian_day?;
 
-        if !self.info.validate(&mut res) {
-            Err(ParseError::UnrecognizedFormat)
-        } else if fuzzy_with_tokens {
-            let skipped_tokens = self.recombine_skipped(skipped_idxs, l);
-            Ok((res, Some(skipped_tokens)))
+        let mut num_idx = idx + 1;
+        while num_idx < tokens.len() && tokens[num_idx] == " " {
+            num_idx += 1;
+        }
+        if num_idx < tokens.len() && tokens[num_idx] == "-" {
+            num_idx += 1;
+        }
+
+        if num_idx < tokens.len() && split_timestamp(&tokens[num_idx]).is_some() {
+            Some(num_idx)
         } else {
-            Ok((res, None))
+            None
+        }
+    }
+
+    fn assign_julian_day(
+        &self,
+        tokens: &[Cow<str>],
+        idx: usize,
+        num_idx: usize,
+        ymd: &mut YMD,
+        res: &mut ParsingResult,
+    ) -> ParseResult<()> {
+        // UNWRAP: `julian_day_number` has already checked both of these
+        let kind = self.vocab.lookup(&tokens[idx]).julian_day.unwrap();
+        let (_, int, frac) = split_timestamp(&tokens[num_idx]).unwrap();
+        let negative = tokens[num_idx - 1] == "-";
+
+        let ((year, month, day), nanos) = calendar::from_julian_day(kind, negative, int, frac)
+            .filter(|&((year, _, _), _)| i64::from(year as i32) == year)
+            .ok_or(ParseError::ImpossibleTimestamp("Julian day out of range"))?;
+
+        let year = year.to_string();
+        ymd.append(year.parse::<i32>()?, &year, Some(YMDLabel::Year))?;
+        ymd.append(month as i32, &tokens[idx], Some(YMDLabel::Month))?;
+        ymd.append(day as i32, &tokens[idx], Some(YMDLabel::Day))?;
+        // Julian days always name the exact year, even in the first century
+        ymd.century_specified = true;
+
+        let seconds = nanos / 1_000_000_000;
+        res.hour = Some((seconds / 3600) as i32);
+        res.minute = Some((seconds / 60 % 60) as i32);
+        res.second = Some((seconds % 60) as i32);
+        res.nanosecond = Some(nanos % 1_000_000_000);
+
+        Ok(())
+    }
+
+    /// If `tokens[idx]` is the sign of an expanded year starting a date, like
+    /// "+002024-03-05" or "-000044-03-15", the index of its digits
+    fn signed_year(
+        &self,
+        tokens: &[Cow<str>],
+        idx: usize,
+        ymd: &YMD,
+        res: &ParsingResult,
+    ) -> Option<usize> {
+        if !(tokens[idx] == "+" || tokens[idx] == "-") || ymd.len() > 0 || res.hour.is_some() {
+            return None;
         }
+
+        let digits = tokens.get(idx + 1)?;
+        let expected = match self.year_digits {
+            Some(year_digits) => digits.len() == year_digits,
+            None => digits.len() >= 4,
+        };
+        if expected
+            && digits.bytes().all(|b| b.is_ascii_digit())
+            && tokens.get(idx + 2).is_some_and(|token| token == "-")
+        {
+            Some(idx + 1)
+        } else {
+            None
+        }
+    }
+
+    /// If `tokens[idx]` starts a dual-dated year like "1709/10", the later
+    /// year. Dates from January 1st to March 24th were written this way while
+    /// the legal year still began on March 25th; the later year is the one
+    /// counted from January 1st. Only read once the month is known, so
+    /// "2011/12" alone is still December 2011.
+    fn split_year(&self, tokens: &[Cow<str>], idx: usize, ymd: &YMD) -> Option<i32> {
+        if ymd.mstridx.is_none() || ymd.ystridx.is_some() || ymd.len() > 2 {
+            return None;
+        }
+
+        let first = &tokens[idx];
+        let later = tokens.get(idx + 2)?;
+        if first.len() != 4
+            || tokens[idx + 1] != "/"
+            || !(1..=2).contains(&later.len())
+            || !first.bytes().chain(later.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let year = first.parse::<i32>().ok()? + 1;
+        let modulus = 10i32.pow(later.len() as u32);
+        if later.parse::<i32>().ok()? == year % modulus {
+            Some(year)
+        } else {
+            None
+        }
+    }
+
+    /// If `tokens[idx]` names a regnal era starting a date, the era's index,
+    /// the index of the token with its year and the year within the era.
+    /// The year may follow the name after a space, or be part of the same
+    /// token for the first year, as in "令和元年".
+    fn regnal_year(
+        &self,
+        tokens: &[Cow<str>],
+        idx: usize,
+        ymd: &YMD,
+        res: &ParsingResult,
+    ) -> Option<(usize, usize, i32)> {
+        let (era, first_year) = self.vocab.lookup(&tokens[idx]).regnal_era?;
+        if ymd.len() > 0 || res.hour.is_some() {
+            return None;
+        }
+        if first_year {
+            return Some((era, idx, 1));
+        }
+
+        let next = if tokens.get(idx + 1)? == " " { idx + 2 } else { idx + 1 };
+        // Not "T10:30"
+        if tokens.get(next + 1).is_some_and(|token| token == ":") {
+            return None;
+        }
+        let year = tokens.get(next)?.parse::<i32>().ok().filter(|&year| year >= 1)?;
+        Some((era, next, year))
+    }
+
+    /// Find the year marked by the era or calendar marker from `tokens[idx]`
+    /// to `tokens[end]`, which is the year already found or a number just
+    /// before or after the marker. Returns the index of the last token used.
+    fn assign_marked_year(
+        &self,
+        tokens: &[Cow<str>],
+        idx: usize,
+        end: usize,
+        ymd: &mut YMD,
+        res: &mut ParsingResult,
+    ) -> ParseResult<usize> {
+        if ymd.ystridx.is_some() {
+            return Ok(end);
+        }
+
+        // 44 BC
+        let prev = tokens[..idx].iter().rposition(|token| token != " ");
+        if let (Some(prev), Some(&last)) = (prev, ymd._ymd.last()) {
+            let last_idx = ymd.len() - 1;
+            if tokens[prev].parse::<i32>().ok() == Some(last)
+                && ymd.mstridx != Some(last_idx)
+                && ymd.dstridx != Some(last_idx)
+            {
+                ymd.ystridx = Some(last_idx);
+                ymd.century_specified = true;
+                return Ok(end);
+            }
+        }
+
+        // AD 1066, but not AD 12:08
+        let next = (end + 1..tokens.len()).find(|&tok| tokens[tok].chars().any(char::is_alphanumeric));
+        if let Some(next) = next {
+            if let Ok(year) = tokens[next].parse::<i32>() {
+                if tokens.get(next + 1).is_none_or(|token| token != ":") {
+                    res.mark_ymd(next, 0, tokens[next].len(), ymd);
+                    ymd.append(year, &tokens[next], Some(YMDLabel::Year))?;
+                    ymd.century_specified = true;
+                    return Ok(next);
+                }
+            }
+        }
+
+        Ok(end)
     }
 
     fn could_be_tzname(
@@ -920,7 +2282,8 @@ impl Parser {
             && tzname.is_none()
             && tzoffset.is_none()
             && token.len() <= 5
-            && (all_ascii_upper || self.info.utczone.contains_key(token))
+            && (all_ascii_upper
+                || (self.vocab.lookup(token).utczone && !token.chars().any(char::is_uppercase)))
     }
 
     #[allow(clippy::unnecessary_unwrap)]
@@ -944,60 +2307,167 @@ impl Parser {
         Ok(val_is_ampm)
     }
 
+    /// Build the timestamp and offset for a parsed string, as `parse` returns them
+    fn build_result(
+        &self,
+        res: &ParsingResult,
+        default: &NaiveDateTime,
+        ignoretz: bool,
+        tzinfos: &HashMap<String, i32>,
+    ) -> ParseResult<(NaiveDateTime, Option<FixedOffset>)> {
+        if res.len() == 0 {
+            return Err(ParseError::NoDate);
+        }
+
+        let naive = self.build_naive(res, default)?;
+
+        let offset = if !ignoretz {
+            self.build_tzaware(&naive, res, tzinfos)?
+        } else {
+            None
+        };
+
+        if self.validate_leap_seconds && naive.nanosecond() >= 1_000_000_000 {
+            let utc_offset = offset
+                .map(|offset| offset.local_minus_utc())
+                .or(res.tzoffset)
+                .unwrap_or(0);
+            if !leap_second::is_known_leap_second(naive, utc_offset) {
+                return Err(ParseError::ImpossibleTimestamp("Invalid leap second"));
+            }
+        }
+
+        Ok((naive, offset))
+    }
+
     fn build_naive(
         &self,
         res: &ParsingResult,
         default: &NaiveDateTime,
     ) -> ParseResult<NaiveDateTime> {
-        let y = res.year.unwrap_or_else(|| default.year());
-        let m = res.month.unwrap_or_else(|| default.month() as i32) as u32;
+        let d = self.build_date(res, default)?;
 
-        let d_offset = if res.weekday.is_some() && res.day.is_none() {
-            let dow = day_of_week(y as u32, m, default.day())?;
+        if let Some(era) = res.regnal_era {
+            let eras = &self.info.regnal_eras;
+            if d < eras[era].start || eras.get(era + 1).is_some_and(|next| d >= next.start) {
+                return Err(ParseError::ImpossibleTimestamp("Date outside era"));
+            }
+        }
 
-            // UNWRAP: We've already check res.weekday() is some
-            let actual_weekday = (res.weekday.unwrap() + 1) % 7;
-            let other = DayOfWeek::from_numeral(actual_weekday as u32);
-            Duration::days(i64::from(dow.difference(&other)))
+        let d_offset = if let (Some(weekday), None) = (res.weekday, res.day) {
+            // The next `weekday` on or after the date, as 0 for Monday
+            let dow = d.weekday().num_days_from_monday();
+            Duration::days((weekday as i64 - i64::from(dow)).rem_euclid(7))
         } else {
             Duration::days(0)
         };
-
-        // TODO: Change month/day to u32
-        let d = NaiveDate::from_ymd_opt(
-            y,
-            m,
-            min(
-                res.day.unwrap_or(default.day() as i32) as u32,
-                days_in_month(y, m as i32)?,
-            ),
-        )
-        .ok_or_else(|| ParseError::ImpossibleTimestamp("Invalid date range given"))?;
-
-        let d = d + d_offset;
+        let d = d
+            .checked_add_signed(d_offset)
+            .ok_or(ParseError::ImpossibleTimestamp("Invalid date range given"))?;
 
         let hour = res.hour.unwrap_or(default.hour() as i32) as u32;
         let minute = res.minute.unwrap_or(default.minute() as i32) as u32;
-        let second = res.second.unwrap_or(default.second() as i32) as u32;
-        let nanosecond = res
+        let mut second = res.second.unwrap_or(default.second() as i32) as u32;
+        let mut nanosecond = res
             .nanosecond
-            .unwrap_or(default.timestamp_subsec_nanos() as i64) as u32;
-        let t =
-            NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond).ok_or_else(|| {
-                if hour >= 24 {
-                    ParseError::ImpossibleTimestamp("Invalid hour")
-                } else if minute >= 60 {
-                    ParseError::ImpossibleTimestamp("Invalid minute")
-                } else if second >= 60 {
-                    ParseError::ImpossibleTimestamp("Invalid second")
-                } else if nanosecond >= 2_000_000_000 {
-                    ParseError::ImpossibleTimestamp("Invalid microsecond")
-                } else {
-                    unreachable!();
-                }
-            })?;
+            .unwrap_or(default.nanosecond() as i64) as u32;
+        let carry = nanosecond == 1_000_000_000;
+        if carry {
+            nanosecond = 0;
+        }
+        // chrono keeps a leap second as the second before it, overflowing
+        // into the next billion nanoseconds
+        if second == 60 {
+            second = 59;
+            nanosecond += 1_000_000_000;
+        }
+        let t = NaiveTime::from_hms_nano_opt(hour, minute, second, nanosecond).ok_or(
+            if hour >= 24 {
+                ParseError::ImpossibleTimestamp("Invalid hour")
+            } else if minute >= 60 {
+                ParseError::ImpossibleTimestamp("Invalid minute")
+            } else if second >= 60 {
+                ParseError::ImpossibleTimestamp("Invalid second")
+            } else {
+                ParseError::ImpossibleTimestamp("Invalid microsecond")
+            },
+        )?;
+
+        let naive = NaiveDateTime::new(d, t);
+        if carry {
+            // Step to the start of the next second, which for a leap second
+            // is the start of the next minute
+            naive
+                .with_nanosecond(0)
+                .and_then(|whole| whole.checked_add_signed(Duration::seconds(1)))
+                .ok_or(ParseError::ImpossibleTimestamp("Invalid second"))
+        } else {
+            Ok(naive)
+        }
+    }
+
+    /// The date in `res`, filled in from `default` and converted to the
+    /// proleptic Gregorian calendar
+    fn build_date(&self, res: &ParsingResult, default: &NaiveDateTime) -> ParseResult<NaiveDate> {
+        if let Some(ref calendar) = self.calendar {
+            let (year, month, day) = calendar.ymd(default.date());
+            let y = res.year.unwrap_or(year);
+            let m = res.month.map_or(month, |m| m as u32);
+            let month_days = calendar
+                .days_in_month(y, m)
+                .ok_or(ParseError::ImpossibleTimestamp("Invalid month"))?;
+            let day = min(res.day.map_or(day, |d| d as u32), month_days);
+            return calendar
+                .to_gregorian(y, m, day)
+                .ok_or(ParseError::ImpossibleTimestamp("Invalid date range given"));
+        }
+
+        let y = res.year.unwrap_or_else(|| default.year());
+        if y < NaiveDate::MIN.year() || y > NaiveDate::MAX.year() {
+            return Err(ParseError::ImpossibleTimestamp("Year out of range"));
+        }
+        let m = res.month.unwrap_or_else(|| default.month() as i32) as u32;
 
-        Ok(NaiveDateTime::new(d, t))
+        // TODO: Change month/day to u32
+        let system = self.calendar_system(res, default);
+        let month_days = match system {
+            CalendarSystem::Julian => calendar::julian_days_in_month(y, m)
+                .ok_or(ParseError::ImpossibleTimestamp("Invalid month"))?,
+            _ => days_in_month(y, m as i32)?,
+        };
+        let day = min(res.day.unwrap_or(default.day() as i32) as u32, month_days);
+        let (y, m, day) = match system {
+            CalendarSystem::Julian => calendar::julian_to_gregorian(y, m, day)
+                .ok_or(ParseError::ImpossibleTimestamp("Invalid date range given"))?,
+            _ => (y, m, day),
+        };
+
+        NaiveDate::from_ymd_opt(y, m, day)
+            .ok_or(ParseError::ImpossibleTimestamp("Invalid date range given"))
+    }
+
+    /// The calendar `res` was written in: the one set with `with_calendar`,
+    /// the one it was marked with, or the Julian calendar for dates before
+    /// the reform date, if one was set
+    fn calendar_system(&self, res: &ParsingResult, default: &NaiveDateTime) -> CalendarSystem {
+        if self.calendar.is_some() {
+            return CalendarSystem::Custom;
+        }
+        let written = (
+            res.year.unwrap_or_else(|| default.year()),
+            res.month.unwrap_or_else(|| default.month() as i32) as u32,
+            res.day.unwrap_or(default.day() as i32) as u32,
+        );
+        let julian = res.julian.unwrap_or_else(|| {
+            self.julian_reform
+                .is_some_and(|reform| written < (reform.year(), reform.month(), reform.day()))
+        });
+
+        if julian {
+            CalendarSystem::Julian
+        } else {
+            CalendarSystem::Gregorian
+        }
     }
 
     fn build_tzaware(
@@ -1008,11 +2478,11 @@ impl Parser {
     ) -> ParseResult<Option<FixedOffset>> {
         if let Some(offset) = res.tzoffset {
             Ok(FixedOffset::east_opt(offset))
-        } else if res.tzoffset == None
+        } else if res.tzoffset.is_none()
             && (res.tzname == Some(" ".to_owned())
                 || res.tzname == Some(".".to_owned())
                 || res.tzname == Some("-".to_owned())
-                || res.tzname == None)
+                || res.tzname.is_none())
         {
             Ok(None)
         } else if res.tzname.is_some() && tzinfos.contains_key(res.tzname.as_ref().unwrap()) {
@@ -1027,92 +2497,123 @@ impl Parser {
         }
     }
 
-    #[allow(clippy::unnecessary_unwrap)]
+    #[allow(clippy::unnecessary_unwrap, clippy::too_many_arguments)]
     fn parse_numeric_token(
         &self,
-        tokens: &[String],
+        tokens: &[Cow<str>],
         idx: usize,
-        info: &ParserInfo,
+        value: Number,
+        vocab: &Vocabulary,
         ymd: &mut YMD,
         res: &mut ParsingResult,
         fuzzy: bool,
     ) -> ParseResult<usize> {
         let mut idx = idx;
         let value_repr = &tokens[idx];
-        let mut value = Decimal::from_str(&value_repr).unwrap();
 
         let len_li = value_repr.len();
         let len_l = tokens.len();
 
         // TODO: I miss the `x in y` syntax
         // TODO: Decompose this logic a bit
-        if ymd.len() == 3
+        if self.year_digits == Some(len_li) && value.is_integer() && ymd.ystridx.is_none() {
+            // 012345-01-01, with six-digit years
+            res.mark(idx, 0, len_li, FormatField::Unsupported);
+            ymd.append(expanded_year(value_repr, false)?, value_repr, Some(YMDLabel::Year))?;
+        } else if let Some(year) = self.split_year(tokens, idx, ymd) {
+            // 10 February 1709/10
+            for (tok, token) in tokens.iter().enumerate().skip(idx).take(3) {
+                res.mark(tok, 0, token.len(), FormatField::Unsupported);
+            }
+            ymd.append(year, value_repr, Some(YMDLabel::Year))?;
+            idx += 2;
+        } else if ymd.len() == 3
             && (len_li == 2 || len_li == 4)
             && res.hour.is_none()
             && (idx + 1 >= len_l
-                || (tokens[idx + 1] != ":" && info.hms_index(&tokens[idx + 1]).is_none()))
+                || (tokens[idx + 1] != ":" && vocab.lookup(&tokens[idx + 1]).hms.is_none()))
         {
             // 1990101T32[59]
             let s = &tokens[idx];
             res.hour = s[0..2].parse::<i32>().ok();
+            res.mark(idx, 0, 2, FormatField::Hour);
 
             if len_li == 4 {
-                res.minute = Some(s[2..4].parse::<i32>()?)
+                res.minute = Some(s[2..4].parse::<i32>()?);
+                res.mark(idx, 2, 4, FormatField::Minute);
             }
-        } else if len_li == 6 || (len_li > 6 && tokens[idx].find('.') == Some(6)) {
+        } else if (len_li == 6 && !tokens[idx].contains('.')) || tokens[idx].find('.') == Some(6) {
             // YYMMDD or HHMMSS[.ss]
             let s = &tokens[idx];
 
-            if ymd.len() == 0 && tokens[idx].find('.') == None {
+            if ymd.len() == 0 && !tokens[idx].contains('.') {
+                res.mark_ymd(idx, 0, 2, ymd);
                 ymd.append(s[0..2].parse::<i32>()?, &s[0..2], None)?;
+                res.mark_ymd(idx, 2, 4, ymd);
                 ymd.append(s[2..4].parse::<i32>()?, &s[2..4], None)?;
+                res.mark_ymd(idx, 4, 6, ymd);
                 ymd.append(s[4..6].parse::<i32>()?, &s[4..6], None)?;
             } else {
                 // 19990101T235959[.59]
                 res.hour = s[0..2].parse::<i32>().ok();
                 res.minute = s[2..4].parse::<i32>().ok();
+                res.mark(idx, 0, 2, FormatField::Hour);
+                res.mark(idx, 2, 4, FormatField::Minute);
 
-                let t = self.parsems(&s[4..])?;
-                res.second = Some(t.0);
-                res.nanosecond = Some(t.1);
+                self.assign_seconds(res, &s[4..])?;
+                res.mark_seconds(idx, 4, &s[4..]);
             }
-        } else if vec![8, 12, 14].contains(&len_li) {
+        } else if [8, 12, 14].contains(&len_li) && !tokens[idx].contains('.') {
             // YYMMDD
             let s = &tokens[idx];
+            res.mark_ymd(idx, 0, 4, ymd);
             ymd.append(s[..4].parse::<i32>()?, &s[..4], Some(YMDLabel::Year))?;
+            res.mark_ymd(idx, 4, 6, ymd);
             ymd.append(s[4..6].parse::<i32>()?, &s[4..6], None)?;
+            res.mark_ymd(idx, 6, 8, ymd);
             ymd.append(s[6..8].parse::<i32>()?, &s[6..8], None)?;
 
             if len_li > 8 {
                 res.hour = Some(s[8..10].parse::<i32>()?);
                 res.minute = Some(s[10..12].parse::<i32>()?);
+                res.mark(idx, 8, 10, FormatField::Hour);
+                res.mark(idx, 10, 12, FormatField::Minute);
 
                 if len_li > 12 {
                     res.second = Some(s[12..].parse::<i32>()?);
+                    res.mark(idx, 12, len_li, FormatField::Second);
                 }
             }
-        } else if let Some(hms_idx) = self.find_hms_index(idx, tokens, info, true) {
+        } else if let Some(hms_idx) = self.find_hms_index(idx, tokens, vocab, true) {
             // HH[ ]h or MM[ ]m or SS[.ss][ ]s
-            let (new_idx, hms) = self.parse_hms(idx, tokens, info, Some(hms_idx));
+            let (new_idx, hms) = self.parse_hms(idx, tokens, vocab, Some(hms_idx));
             if let Some(hms) = hms {
                 self.assign_hms(res, value_repr, hms)?;
+                match hms {
+                    0 if !value_repr.contains('.') => res.mark(idx, 0, len_li, FormatField::Hour),
+                    1 if !value_repr.contains('.') => res.mark(idx, 0, len_li, FormatField::Minute),
+                    2 => res.mark_seconds(idx, 0, value_repr),
+                    _ => res.mark(idx, 0, len_li, FormatField::Unsupported),
+                }
             }
             idx = new_idx;
         } else if idx + 2 < len_l && tokens[idx + 1] == ":" {
             // HH:MM[:SS[.ss]]
-            // TODO: Better story around Decimal handling
-            res.hour = Some(value.floor().to_i64().unwrap() as i32);
-            // TODO: Rescope `value` here?
-            value = self.to_decimal(&tokens[idx + 2])?;
-            let min_sec = self.parse_min_sec(value);
-            res.minute = Some(min_sec.0);
-            res.second = min_sec.1;
+            res.hour = Some(value.to_i32().ok_or_else(|| ParseError::InvalidNumeric(value_repr.to_string()))?);
+            self.assign_min_sec(res, self.to_number(&tokens[idx + 2])?)?;
+
+            // Fractional hours or minutes have no strftime equivalent
+            let (hour_field, minute_field) = match (value_repr.contains('.'), tokens[idx + 2].contains('.')) {
+                (false, false) => (FormatField::Hour, FormatField::Minute),
+                _ => (FormatField::Unsupported, FormatField::Unsupported),
+            };
+            res.mark(idx, 0, len_li, hour_field);
+            res.mark(idx + 2, 0, tokens[idx + 2].len(), minute_field);
 
             if idx + 4 < len_l && tokens[idx + 3] == ":" {
                 // TODO: (x, y) = (a, b) syntax?
-                let ms = self.parsems(&tokens[idx + 4]).unwrap();
-                res.second = Some(ms.0);
-                res.nanosecond = Some(ms.1);
+                self.assign_seconds(res, &tokens[idx + 4])?;
+                res.mark_seconds(idx + 4, 0, &tokens[idx + 4]);
 
                 idx += 2;
             }
@@ -1122,21 +2623,29 @@ impl Parser {
         {
             // TODO: There's got to be a better way of handling the condition above
             let sep = &tokens[idx + 1];
-            ymd.append(value_repr.parse::<i32>()?, &value_repr, None)?;
+            // 2024-03-05
+            ymd.iso_order = ymd.len() == 0 && sep == "-" && len_li == 4 && !value_repr.contains('.');
+            res.mark_ymd(idx, 0, len_li, ymd);
+            ymd.append(value_repr.parse::<i32>()?, value_repr, None)?;
 
-            if idx + 2 < len_l && !info.jump_index(&tokens[idx + 2]) {
+            if idx + 2 < len_l && !vocab.lookup(&tokens[idx + 2]).jump {
+                let len_next = tokens[idx + 2].len();
                 if let Ok(val) = tokens[idx + 2].parse::<i32>() {
+                    res.mark_ymd(idx + 2, 0, len_next, ymd);
                     ymd.append(val, &tokens[idx + 2], None)?;
-                } else if let Some(val) = info.month_index(&tokens[idx + 2]) {
+                } else if let Some(val) = vocab.lookup(&tokens[idx + 2]).month {
+                    res.mark_ymd(idx + 2, 0, len_next, ymd);
                     ymd.append(val as i32, &tokens[idx + 2], Some(YMDLabel::Month))?;
                 }
 
                 if idx + 3 < len_l && &tokens[idx + 3] == sep {
                     if tokens.len() <= idx + 4 {
                         return Err(ParseError::UnrecognizedFormat);
-                    } else if let Some(value) = info.month_index(&tokens[idx + 4]) {
+                    } else if let Some(value) = vocab.lookup(&tokens[idx + 4]).month {
+                        res.mark_ymd(idx + 4, 0, tokens[idx + 4].len(), ymd);
                         ymd.append(value as i32, &tokens[idx + 4], Some(YMDLabel::Month))?;
                     } else if let Ok(val) = tokens[idx + 4].parse::<i32>() {
+                        res.mark_ymd(idx + 4, 0, tokens[idx + 4].len(), ymd);
                         ymd.append(val, &tokens[idx + 4], None)?;
                     } else {
                         return Err(ParseError::UnrecognizedFormat);
@@ -1149,28 +2658,30 @@ impl Parser {
             }
 
             idx += 1
-        } else if idx + 1 >= len_l || info.jump_index(&tokens[idx + 1]) {
-            if idx + 2 < len_l && info.ampm_index(&tokens[idx + 2]).is_some() {
-                let hour = value.to_i64().unwrap() as i32;
-                let ampm = info.ampm_index(&tokens[idx + 2]).unwrap();
+        } else if idx + 1 >= len_l || vocab.lookup(&tokens[idx + 1]).jump {
+            if idx + 2 < len_l && vocab.lookup(&tokens[idx + 2]).ampm.is_some() {
+                let hour = value.to_i32().ok_or_else(|| ParseError::InvalidNumeric(value_repr.to_string()))?;
+                let ampm = vocab.lookup(&tokens[idx + 2]).ampm.unwrap();
                 res.hour = Some(self.adjust_ampm(hour, ampm));
+                res.mark(idx, 0, len_li, FormatField::Hour);
                 idx += 1;
             } else {
-                //let value = value.floor().to_i32().ok_or(Err(ParseError::InvalidNumeric()))
-                let value = value.floor().to_i32().ok_or_else(|| ParseError::InvalidNumeric(value_repr.to_owned()))?;
-                ymd.append(value, &value_repr, None)?;
+                let value = value.to_i32().ok_or_else(|| ParseError::InvalidNumeric(value_repr.to_string()))?;
+                res.mark_ymd(idx, 0, len_li, ymd);
+                ymd.append(value, value_repr, None)?;
             }
 
             idx += 1;
-        } else if info.ampm_index(&tokens[idx + 1]).is_some()
-            && (*ZERO <= value && value < *TWENTY_FOUR)
-        {
+        } else if vocab.lookup(&tokens[idx + 1]).ampm.is_some() && value.int < 24 {
             // 12am
-            let hour = value.to_i64().unwrap() as i32;
-            res.hour = Some(self.adjust_ampm(hour, info.ampm_index(&tokens[idx + 1]).unwrap()));
+            let hour = value.int as i32;
+            res.hour = Some(self.adjust_ampm(hour, vocab.lookup(&tokens[idx + 1]).ampm.unwrap()));
+            res.mark(idx, 0, len_li, FormatField::Hour);
+            res.mark(idx + 1, 0, tokens[idx + 1].len(), FormatField::AmPm);
             idx += 1;
-        } else if ymd.could_be_day(value.to_i64().unwrap() as i32) {
-            ymd.append(value.to_i64().unwrap() as i32, &value_repr, None)?;
+        } else if let Some(day) = value.to_i32().filter(|&day| ymd.could_be_day(day, self.calendar.as_deref())) {
+            res.mark_ymd(idx, 0, len_li, ymd);
+            ymd.append(day, value_repr, None)?;
         } else if !fuzzy {
             return Err(ParseError::UnrecognizedFormat);
         }
@@ -1188,6 +2699,16 @@ impl Parser {
         }
     }
 
+    fn assign_seconds(&self, res: &mut ParsingResult, seconds_str: &str) -> ParseResult<()> {
+        let (sec, nanos) = self.parsems(seconds_str)?;
+        res.second = Some(sec);
+        res.nanosecond = Some(nanos);
+        res.fraction_digits = seconds_str.find('.').map(|dot| seconds_str.len() - dot - 1);
+        Ok(())
+    }
+
+    /// Seconds and nanoseconds, where the nanoseconds are 1_000_000_000 if
+    /// the fraction rounded up to a whole second
     fn parsems(&self, seconds_str: &str) -> ParseResult<(i32, i64)> {
         if seconds_str.contains('.') {
             let split: Vec<&str> = seconds_str.split('.').collect();
@@ -1195,17 +2716,41 @@ impl Parser {
 
             let i_parse = i.parse::<i32>()?;
             let f_parse = ljust(f, 9, '0').parse::<i64>()?;
-            Ok((i_parse, f_parse))
+            let extra = f.get(9..).unwrap_or("");
+            if !extra.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseError::InvalidNumeric(seconds_str.to_owned()));
+            }
+            let up = self.round_up(f_parse % 2 == 1, compare_to_half(extra))?;
+            Ok((i_parse, f_parse + up as i64))
         } else {
             Ok((seconds_str.parse::<i32>()?, 0))
         }
     }
 
+    /// Whether a number of nanoseconds, odd or not, rounds up to the next
+    /// nanosecond given how the part of a nanosecond after it compares to a
+    /// half, if there was any
+    fn round_up(&self, odd: bool, rest: Option<Ordering>) -> ParseResult<bool> {
+        let rest = match rest {
+            Some(rest) => rest,
+            None => return Ok(false),
+        };
+
+        match self.fraction_rounding {
+            FractionRounding::Truncate => Ok(false),
+            FractionRounding::HalfUp => Ok(rest != Ordering::Less),
+            FractionRounding::HalfEven => Ok(rest == Ordering::Greater || (rest == Ordering::Equal && odd)),
+            FractionRounding::Reject => Err(ParseError::ImpossibleTimestamp(
+                "Fractional seconds finer than nanoseconds",
+            )),
+        }
+    }
+
     fn find_hms_index(
         &self,
         idx: usize,
-        tokens: &[String],
-        info: &ParserInfo,
+        tokens: &[Cow<str>],
+        vocab: &Vocabulary,
         allow_jump: bool,
     ) -> Option<usize> {
         let len_l = tokens.len();
@@ -1220,27 +2765,25 @@ impl Parser {
             len_l - 2
         } else if idx > 1 {
             idx - 2
-        } else if len_l == 0 {
-            panic!("Attempting to find_hms_index() wih no tokens.");
         } else {
             0
         };
 
-        if idx + 1 < len_l && info.hms_index(&tokens[idx + 1]).is_some() {
+        if idx + 1 < len_l && vocab.lookup(&tokens[idx + 1]).hms.is_some() {
             hms_idx = Some(idx + 1)
         } else if allow_jump
             && idx + 2 < len_l
             && tokens[idx + 1] == " "
-            && info.hms_index(&tokens[idx + 2]).is_some()
+            && vocab.lookup(&tokens[idx + 2]).hms.is_some()
         {
             hms_idx = Some(idx + 2)
-        } else if idx > 0 && info.hms_index(&tokens[idx - 1]).is_some() {
+        } else if idx > 0 && vocab.lookup(&tokens[idx - 1]).hms.is_some() {
             hms_idx = Some(idx - 1)
         } else if len_l > 0
             && idx > 0
             && idx == len_l - 1
             && tokens[idx - 1] == " "
-            && info.hms_index(&tokens[idx_minus_two]).is_some()
+            && vocab.lookup(&tokens[idx_minus_two]).hms.is_some()
         {
             hms_idx = Some(idx - 2)
         }
@@ -1252,8 +2795,8 @@ impl Parser {
     fn parse_hms(
         &self,
         idx: usize,
-        tokens: &[String],
-        info: &ParserInfo,
+        tokens: &[Cow<str>],
+        vocab: &Vocabulary,
         hms_index: Option<usize>,
     ) -> (usize, Option<usize>) {
         if hms_index.is_none() {
@@ -1261,55 +2804,68 @@ impl Parser {
         } else if hms_index.unwrap() > idx {
             (
                 hms_index.unwrap(),
-                info.hms_index(&tokens[hms_index.unwrap()]),
+                vocab.lookup(&tokens[hms_index.unwrap()]).hms,
             )
         } else {
             (
                 idx,
-                info.hms_index(&tokens[hms_index.unwrap()]).map(|u| u + 1),
+                vocab.lookup(&tokens[hms_index.unwrap()]).hms.map(|u| u + 1),
             )
         }
     }
 
     fn assign_hms(&self, res: &mut ParsingResult, value_repr: &str, hms: usize) -> ParseResult<()> {
-        let value = self.to_decimal(value_repr)?;
+        let value = self.to_number(value_repr)?;
 
         if hms == 0 {
             res.hour = value.to_i32();
-            if !close_to_integer(&value) {
-                res.minute = Some((*SIXTY * (value % *ONE)).to_i64().unwrap() as i32);
+            if !value.is_integer() {
+                let (secs, nanos) = self.fraction_seconds(value, 3600)?;
+                res.minute = Some(secs / 60);
+                if secs % 60 != 0 || nanos != 0 {
+                    res.second = Some(secs % 60);
+                }
+                if nanos != 0 {
+                    res.nanosecond = Some(nanos);
+                }
             }
         } else if hms == 1 {
-            let (min, sec) = self.parse_min_sec(value);
-            res.minute = Some(min);
-            res.second = sec;
+            self.assign_min_sec(res, value)?;
         } else if hms == 2 {
-            let (sec, micro) = self.parsems(value_repr).unwrap();
-            res.second = Some(sec);
-            res.nanosecond = Some(micro);
+            self.assign_seconds(res, value_repr)?;
         }
 
         Ok(())
     }
 
-    fn to_decimal(&self, value: &str) -> ParseResult<Decimal> {
-        Decimal::from_str(value).or_else(|_| Err(ParseError::InvalidNumeric(value.to_owned())))
+    fn to_number(&self, value: &str) -> ParseResult<Number> {
+        Number::parse(value).ok_or_else(|| ParseError::InvalidNumeric(value.to_owned()))
     }
 
-    fn parse_min_sec(&self, value: Decimal) -> (i32, Option<i32>) {
-        // UNWRAP: i64 guaranteed to be fine because of preceding floor
-        let minute = value.floor().to_i64().unwrap() as i32;
-        let mut second = None;
+    fn assign_min_sec(&self, res: &mut ParsingResult, value: Number) -> ParseResult<()> {
+        res.minute = Some(value.to_i32().ok_or(ParseError::ImpossibleTimestamp("Invalid minute"))?);
 
-        let sec_remainder = value - value.floor();
-        if sec_remainder != *ZERO {
-            second = Some((*SIXTY * sec_remainder).floor().to_i64().unwrap() as i32);
+        if !value.is_integer() {
+            let (secs, nanos) = self.fraction_seconds(value, 60)?;
+            res.second = Some(secs);
+            if nanos != 0 {
+                res.nanosecond = Some(nanos);
+            }
         }
 
-        (minute, second)
+        Ok(())
     }
 
-    fn recombine_skipped(&self, skipped_idxs: Vec<usize>, tokens: Vec<String>) -> Vec<String> {
+    /// The fraction of `value`, a number of units `scale` seconds long, as
+    /// whole seconds and nanoseconds. Parts of a nanosecond are rounded as set
+    /// with `with_fraction_rounding`, so the nanoseconds can be 1_000_000_000.
+    fn fraction_seconds(&self, value: Number, scale: u128) -> ParseResult<(i32, i64)> {
+        let (nanos, rest) = value.fraction_nanos(scale);
+        let up = self.round_up(nanos % 2 == 1, rest)?;
+        Ok(((nanos / 1_000_000_000) as i32, (nanos % 1_000_000_000) as i64 + up as i64))
+    }
+
+    fn recombine_skipped(&self, skipped_idxs: Vec<usize>, tokens: Vec<Cow<str>>) -> Vec<String> {
         let mut skipped_tokens: Vec<String> = vec![];
 
         let mut sorted_idxs = skipped_idxs.clone();
@@ -1322,7 +2878,7 @@ impl Parser {
                 t.push_str(tokens[*idx].as_ref());
                 skipped_tokens.push(t);
             } else {
-                skipped_tokens.push(tokens[*idx].to_owned());
+                skipped_tokens.push(tokens[*idx].to_string());
             }
         }
 
@@ -1330,15 +2886,36 @@ impl Parser {
     }
 }
 
-fn close_to_integer(value: &Decimal) -> bool {
-    value % *ONE == *ZERO
+/// The year written as `digits`, negated if `negative`, if chrono can
+/// represent it
+fn expanded_year(digits: &str, negative: bool) -> ParseResult<i32> {
+    let range = i64::from(NaiveDate::MIN.year())..=i64::from(NaiveDate::MAX.year());
+    digits
+        .parse::<i64>()
+        .ok()
+        .map(|year| if negative { -year } else { year })
+        .filter(|year| range.contains(year))
+        .map(|year| year as i32)
+        .ok_or(ParseError::ImpossibleTimestamp("Year out of range"))
+}
+
+/// How the digits of a fraction compare to a half, so "5" is equal and "51"
+/// greater; `None` when there are no digits
+fn compare_to_half(digits: &str) -> Option<Ordering> {
+    let mut digits = digits.bytes();
+    let first = digits.next()?;
+    Some(match first.cmp(&b'5') {
+        Ordering::Equal if digits.any(|b| b != b'0') => Ordering::Greater,
+        order => order,
+    })
 }
 
 fn ljust(s: &str, chars: usize, replace: char) -> String {
-    if s.len() >= chars {
-        s[..chars].to_owned()
+    let len = s.chars().count();
+    if len >= chars {
+        s.chars().take(chars).collect()
     } else {
-        format!("{}{}", s, replace.to_string().repeat(chars - s.len()))
+        format!("{}{}", s, replace.to_string().repeat(chars - len))
     }
 }
 
@@ -0,0 +1,146 @@
+//! Exact fixed-point parsing of the numeric tokens produced by the tokenizer,
+//! such as "2003", "09" or "41.502".
+
+use std::cmp::Ordering;
+
+/// Most digits accepted before the decimal point, matching the precision of
+/// the `Decimal` type the parser originally used
+const MAX_INT_DIGITS: usize = 28;
+
+/// Digits of a fraction that are kept; the rest are truncated. Enough that
+/// scaling the fraction by a few thousand can't overflow a `u128`.
+const MAX_FRAC_DIGITS: usize = 30;
+
+/// A non-negative decimal number, split into its integer part and a fraction
+/// of `frac / 10^frac_digits`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Number {
+    pub(crate) int: u128,
+    frac: u128,
+    frac_digits: u32,
+}
+
+impl Number {
+    /// Parse ASCII digits with at most one decimal point, and at least one
+    /// digit on either side of it
+    pub(crate) fn parse(token: &str) -> Option<Number> {
+        let (int, frac) = match token.find('.') {
+            Some(dot) => (&token[..dot], &token[dot + 1..]),
+            None => (token, ""),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return None;
+        }
+        if !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let int_digits = int.trim_start_matches('0');
+        if int_digits.len() > MAX_INT_DIGITS {
+            return None;
+        }
+        let frac = &frac[..frac.len().min(MAX_FRAC_DIGITS)];
+
+        Some(Number {
+            int: digits_value(int_digits),
+            frac: digits_value(frac),
+            frac_digits: frac.len() as u32,
+        })
+    }
+
+    pub(crate) fn is_integer(self) -> bool {
+        self.frac == 0
+    }
+
+    /// The integer part, if it fits in an `i32`
+    pub(crate) fn to_i32(self) -> Option<i32> {
+        if self.int <= i32::MAX as u128 {
+            Some(self.int as i32)
+        } else {
+            None
+        }
+    }
+
+    /// The fraction as nanoseconds of a unit `scale` seconds long, rounded
+    /// down, so 0.25 of a minute is 15_000_000_000. Also gives how the part of
+    /// a nanosecond left over compares to a half, or `None` when nothing is.
+    /// Exact for every fraction digit kept.
+    pub(crate) fn fraction_nanos(self, scale: u128) -> (u128, Option<Ordering>) {
+        let scaled = self.frac * scale;
+        if self.frac_digits <= 9 {
+            return (scaled * 10u128.pow(9 - self.frac_digits), None);
+        }
+
+        let divisor = 10u128.pow(self.frac_digits - 9);
+        let rest = scaled % divisor;
+        let half = if rest == 0 { None } else { Some((rest * 2).cmp(&divisor)) };
+        (scaled / divisor, half)
+    }
+}
+
+fn digits_value(digits: &str) -> u128 {
+    digits
+        .bytes()
+        .fold(0, |acc, b| acc * 10 + u128::from(b - b'0'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Number;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn parses_tokens() {
+        let n = Number::parse("41.502").unwrap();
+        assert_eq!(n.int, 41);
+        assert!(!n.is_integer());
+        assert_eq!(n.fraction_nanos(1), (502_000_000, None));
+
+        let n = Number::parse("0009").unwrap();
+        assert_eq!((n.int, n.is_integer()), (9, true));
+        assert_eq!(Number::parse("5.").map(|n| n.int), Some(5));
+        assert_eq!(Number::parse(".5").map(|n| n.fraction_nanos(60).0), Some(30_000_000_000));
+    }
+
+    #[test]
+    fn rejects_non_numbers() {
+        for token in &["", ".", "1.2.3", "1a", "-1", "+1", "1_000", "٣", " 1"] {
+            assert_eq!(Number::parse(token), None, "Parsed '{}'", token);
+        }
+    }
+
+    #[test]
+    fn limits_precision() {
+        assert!(Number::parse("9999999999999999999999999999").is_some());
+        assert!(Number::parse("99999999999999999999999999999").is_none());
+        assert!(Number::parse("0000000000000000000000000000001").is_some());
+
+        let n = Number::parse("99999999999999999999999").unwrap();
+        assert_eq!(n.to_i32(), None);
+    }
+
+    #[test]
+    fn exact_fractions() {
+        // 1/60 of a minute plus a tiny bit is still a whole second
+        let n = Number::parse("0.016666666666666666666666666667").unwrap();
+        assert_eq!(n.fraction_nanos(60), (1_000_000_000, Some(Ordering::Less)));
+        let n = Number::parse("0.0166666666").unwrap();
+        assert_eq!(n.fraction_nanos(60), (999_999_996, None));
+        let n = Number::parse("12.123456789123").unwrap();
+        assert_eq!(n.fraction_nanos(1), (123_456_789, Some(Ordering::Less)));
+    }
+
+    #[test]
+    fn fraction_nanos() {
+        let nanos = |token, scale| Number::parse(token).unwrap().fraction_nanos(scale);
+        assert_eq!(nanos("1.2345", 3600), (844_200_000_000, None));
+        assert_eq!(nanos("12.3456", 60), (20_736_000_000, None));
+        assert_eq!(nanos("0.1234567891", 1), (123_456_789, Some(Ordering::Less)));
+        assert_eq!(nanos("0.1234567895", 1), (123_456_789, Some(Ordering::Equal)));
+        assert_eq!(nanos("0.12345678951", 1), (123_456_789, Some(Ordering::Greater)));
+        // 0.6 of a nanosecond is left over
+        assert_eq!(nanos("0.00000000001", 60), (0, Some(Ordering::Greater)));
+        let n = Number::parse("0.999999999999999999999999999999").unwrap();
+        assert_eq!(n.fraction_nanos(3600), (3_599_999_999_999, Some(Ordering::Greater)));
+    }
+}
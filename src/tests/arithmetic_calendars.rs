@@ -0,0 +1,163 @@
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+use calendar::Calendar;
+use calendar::Hebrew;
+use calendar::Persian;
+use calendar::TabularIslamic;
+use column::{infer_date_order, parse_column};
+use CalendarSystem;
+use DateOrder;
+use ParseError;
+use Parser;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn midnight(y: i32, m: u32, d: u32) -> NaiveDateTime {
+    date(y, m, d).and_hms_opt(0, 0, 0).unwrap()
+}
+
+fn parse_with(parser: &Parser, timestr: &str) -> Result<NaiveDateTime, ParseError> {
+    let default = midnight(2024, 3, 5);
+    parser
+        .parse(timestr, None, None, false, false, Some(&default), false, &HashMap::new())
+        .map(|res| res.0)
+}
+
+#[test]
+fn persian_dates() {
+    assert_eq!(Persian.to_gregorian(1402, 12, 15), Some(date(2024, 3, 5)));
+    assert_eq!(Persian.to_gregorian(1403, 1, 1), Some(date(2024, 3, 20)));
+    assert_eq!(Persian.to_gregorian(1404, 1, 1), Some(date(2025, 3, 21)));
+    assert_eq!(Persian.to_gregorian(1403, 12, 30), Some(date(2025, 3, 20)));
+    assert_eq!(Persian.to_gregorian(1402, 12, 30), None);
+    assert_eq!(Persian.to_gregorian(1402, 13, 1), None);
+    assert_eq!(Persian.ymd(date(2024, 3, 5)), (1402, 12, 15));
+    assert_eq!(Persian.days_in_month(1403, 7), Some(30));
+}
+
+#[test]
+fn islamic_dates() {
+    assert_eq!(TabularIslamic.to_gregorian(1445, 8, 15), Some(date(2024, 2, 25)));
+    assert_eq!(TabularIslamic.to_gregorian(1445, 1, 1), Some(date(2023, 7, 19)));
+    assert_eq!(TabularIslamic.to_gregorian(1, 1, 1), Some(date(622, 7, 19)));
+    assert_eq!(TabularIslamic.ymd(date(2024, 2, 25)), (1445, 8, 15));
+    // 1445 is the 5th year of its cycle, a leap year
+    assert_eq!(TabularIslamic.days_in_month(1445, 12), Some(30));
+    assert_eq!(TabularIslamic.days_in_month(1444, 12), Some(29));
+    assert_eq!(TabularIslamic.to_gregorian(1445, 2, 30), None);
+}
+
+#[test]
+fn hebrew_dates() {
+    assert_eq!(Hebrew.to_gregorian(5784, 13, 24), Some(date(2024, 4, 3)));
+    assert_eq!(Hebrew.to_gregorian(5784, 1, 15), Some(date(2024, 4, 23)));
+    assert_eq!(Hebrew.to_gregorian(5785, 7, 1), Some(date(2024, 10, 3)));
+    assert_eq!(Hebrew.to_gregorian(5783, 13, 1), None);
+    assert_eq!(Hebrew.ymd(date(2024, 4, 3)), (5784, 13, 24));
+    assert_eq!(Hebrew.ymd(date(2024, 10, 2)), (5784, 6, 29));
+    // 5784 is 383 days long, with 29 days in both Heshvan and Kislev, and
+    // 5785 is 355 days long, with 30 in both
+    assert_eq!(Hebrew.days_in_month(5784, 8), Some(29));
+    assert_eq!(Hebrew.days_in_month(5784, 9), Some(29));
+    assert_eq!(Hebrew.days_in_month(5785, 8), Some(30));
+    assert_eq!(Hebrew.days_in_month(5785, 9), Some(30));
+}
+
+proptest! {
+    #[test]
+    fn round_trips(days in -200_000i64..200_000) {
+        let date = date(2000, 1, 1) + Duration::days(days);
+        let calendars: [&dyn Calendar; 3] = [&Persian, &TabularIslamic, &Hebrew];
+        for calendar in calendars.iter() {
+            let (y, m, d) = calendar.ymd(date);
+            prop_assert!(d >= 1 && Some(d) <= calendar.days_in_month(y, m));
+            prop_assert_eq!(calendar.to_gregorian(y, m, d), Some(date));
+        }
+    }
+}
+
+#[test]
+fn parses_in_calendar() {
+    let persian = Parser::default().with_calendar(Persian);
+    assert_eq!(parse_with(&persian, "1402/12/15"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&persian, "15 Esfand 1402"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&persian, "15 اسفند 1402"), Ok(midnight(2024, 3, 5)));
+
+    let islamic = Parser::default().with_calendar(TabularIslamic);
+    assert_eq!(parse_with(&islamic, "15 Sha'ban 1445"), Ok(midnight(2024, 2, 25)));
+    assert_eq!(parse_with(&islamic, "1 Rabi' al-awwal 1446"), Ok(midnight(2024, 9, 5)));
+    assert_eq!(parse_with(&islamic, "1 Rabi II 1446"), Ok(midnight(2024, 10, 5)));
+    assert_eq!(
+        parse_with(&islamic, "10 Dhu al-Hijjah 1445 12:30"),
+        Ok(midnight(2024, 6, 17) + Duration::minutes(750))
+    );
+
+    let hebrew = Parser::default().with_calendar(Hebrew);
+    assert_eq!(parse_with(&hebrew, "24 Adar II 5784"), Ok(midnight(2024, 4, 3)));
+    assert_eq!(parse_with(&hebrew, "14 Adar 5784"), Ok(midnight(2024, 2, 23)));
+    assert_eq!(parse_with(&hebrew, "1 Tishrei 5785"), Ok(midnight(2024, 10, 3)));
+}
+
+#[test]
+fn fills_in_from_the_calendar() {
+    // The default, 2024-03-05, is 15 Esfand 1402 and 24 Sha'ban 1445
+    let persian = Parser::default().with_calendar(Persian);
+    assert_eq!(parse_with(&persian, "Farvardin 1"), Ok(midnight(2023, 3, 21)));
+    assert_eq!(parse_with(&persian, "1403"), Ok(midnight(2025, 3, 5)));
+    assert_eq!(parse_with(&persian, "1 Farvardin 02"), Ok(midnight(2023, 3, 21)));
+    // Days past the end of the month move back to its last day
+    assert_eq!(parse_with(&persian, "31 Mehr 1402"), Ok(midnight(2023, 10, 22)));
+
+    let islamic = Parser::default().with_calendar(TabularIslamic);
+    assert_eq!(parse_with(&islamic, "Ramadan"), Ok(midnight(2024, 4, 3)));
+    // 5783 wasn't a leap year
+    let hebrew = Parser::default().with_calendar(Hebrew);
+    assert_eq!(
+        parse_with(&hebrew, "1 Adar II 5783"),
+        Err(ParseError::ImpossibleTimestamp("Invalid month"))
+    );
+
+    // English month names are replaced
+    assert!(parse_with(&islamic, "5 March 2024").is_err());
+}
+
+#[test]
+fn reports_custom_calendar() {
+    let parsed = Parser::default()
+        .with_calendar(Hebrew)
+        .parse_scored("24 Adar II 5784", None, None, None, false, &HashMap::new())
+        .unwrap();
+    assert_eq!(parsed.calendar, CalendarSystem::Custom);
+    assert_eq!(parsed.datetime, midnight(2024, 4, 3));
+}
+
+#[test]
+fn date_orders_use_calendar_months() {
+    // Shahrivar has 31 days
+    let persian = || Parser::default().with_calendar(Persian);
+    assert_eq!(infer_date_order(&persian(), &["1402/06/31"]), Ok(DateOrder::YearMonthDay));
+    let (order, parsed) = parse_column(persian(), 1, &["1402/06/31", "1402/06/30"]).unwrap();
+    assert_eq!(order, DateOrder::YearMonthDay);
+    assert_eq!(parsed[0].as_ref().map(|r| r.0), Ok(midnight(2023, 9, 22)));
+    let candidates = persian()
+        .parse_candidates("1402/06/31", false, None, false, &HashMap::new())
+        .unwrap();
+    assert_eq!(candidates.len(), 1);
+    assert_eq!((candidates[0].0, candidates[0].1), (DateOrder::YearMonthDay, midnight(2023, 9, 22)));
+
+    // Adar II is the 13th month of leap years
+    let hebrew = Parser::default().with_calendar(Hebrew);
+    assert_eq!(infer_date_order(&hebrew, &["5784/13/01"]), Ok(DateOrder::YearMonthDay));
+    assert_eq!(infer_date_order(&hebrew, &["5783/13/01"]), Ok(DateOrder::YearDayMonth));
+    let candidates = hebrew
+        .parse_candidates("5784/13/01", false, None, false, &HashMap::new())
+        .unwrap();
+    assert_eq!(candidates[0].0, DateOrder::YearMonthDay);
+    assert_eq!(candidates[0].1, midnight(2024, 3, 11));
+}
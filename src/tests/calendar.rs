@@ -0,0 +1,60 @@
+use chrono::Datelike;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+use calendar;
+use parse;
+use Parser;
+
+fn midnight(y: i32, m: u32, d: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+}
+
+#[test]
+fn century_leap_years() {
+    assert_eq!(parse("2000-02-29"), Ok((midnight(2000, 2, 29), None)));
+    assert_eq!(parse("Feb 29 2000"), Ok((midnight(2000, 2, 29), None)));
+    // Days past the end of the month are clamped to it
+    assert_eq!(parse("Feb 29 1900"), Ok((midnight(1900, 2, 28), None)));
+    assert_eq!(parse("Feb 29 2100"), Ok((midnight(2100, 2, 28), None)));
+}
+
+#[test]
+fn weekday_after_clamping() {
+    // The 31st of February is clamped to the 28th, a Tuesday in 2023, before
+    // moving on to the next Friday
+    let default = midnight(2023, 1, 31);
+    let parsed = Parser::default()
+        .parse("Friday February", None, None, false, false, Some(&default), false, &HashMap::new())
+        .unwrap();
+    assert_eq!(parsed.0, midnight(2023, 3, 3));
+
+    let parsed = Parser::default()
+        .parse("Tuesday February", None, None, false, false, Some(&default), false, &HashMap::new())
+        .unwrap();
+    assert_eq!(parsed.0, midnight(2023, 2, 28));
+}
+
+proptest! {
+    #[test]
+    fn matches_chrono(days in NaiveDate::MIN.num_days_from_ce()..=NaiveDate::MAX.num_days_from_ce()) {
+        let date = NaiveDate::from_num_days_from_ce_opt(days).unwrap();
+        let (y, m, d) = (date.year(), date.month(), date.day());
+
+        prop_assert_eq!(calendar::is_leap_year(y), date.leap_year());
+        prop_assert_eq!(calendar::weekday(y, m, d), Some(date.weekday()));
+        prop_assert_eq!(calendar::day_of_year(y, m, d), Some(date.ordinal()));
+
+        let week = date.iso_week();
+        prop_assert_eq!(calendar::iso_week(y, m, d), Some((week.year(), week.week())));
+
+        let last = date.with_day(1).unwrap().checked_add_months(chrono::Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .map(|last| last.day());
+        if let Some(last) = last {
+            prop_assert_eq!(calendar::days_in_month(y, m), Some(last));
+        }
+    }
+}
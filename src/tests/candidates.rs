@@ -0,0 +1,157 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use DateOrder;
+use ParseError;
+use Parser;
+use ParserInfo;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+}
+
+fn candidates(parser: &Parser, timestr: &str) -> Vec<(DateOrder, NaiveDateTime)> {
+    let default = date(2020, 6, 15);
+    parser
+        .parse_candidates(timestr, false, Some(&default), false, &HashMap::new())
+        .unwrap()
+        .into_iter()
+        .map(|(order, naive, _)| (order, naive))
+        .collect()
+}
+
+#[test]
+fn all_orders() {
+    assert_eq!(
+        candidates(&Parser::default(), "01/02/03"),
+        vec![
+            (DateOrder::MonthDayYear, date(2003, 1, 2)),
+            (DateOrder::DayMonthYear, date(2003, 2, 1)),
+            (DateOrder::YearMonthDay, date(2001, 2, 3)),
+            (DateOrder::YearDayMonth, date(2001, 3, 2)),
+        ]
+    );
+}
+
+#[test]
+fn preferred_order_first() {
+    let info = ParserInfo {
+        dayfirst: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        candidates(&Parser::new(info), "03/04/2024"),
+        vec![
+            (DateOrder::DayMonthYear, date(2024, 4, 3)),
+            (DateOrder::MonthDayYear, date(2024, 3, 4)),
+        ]
+    );
+}
+
+#[test]
+fn invalid_readings_dropped() {
+    assert_eq!(
+        candidates(&Parser::default(), "25/04/2024"),
+        vec![(DateOrder::DayMonthYear, date(2024, 4, 25))]
+    );
+    assert_eq!(
+        candidates(&Parser::default(), "2024-04-30"),
+        vec![(DateOrder::YearMonthDay, date(2024, 4, 30))]
+    );
+}
+
+#[test]
+fn same_date_listed_once() {
+    assert_eq!(
+        candidates(&Parser::default(), "05/05/2024"),
+        vec![(DateOrder::MonthDayYear, date(2024, 5, 5))]
+    );
+    assert_eq!(
+        candidates(&Parser::default(), "03/04"),
+        vec![
+            (DateOrder::MonthDayYear, date(2020, 3, 4)),
+            (DateOrder::DayMonthYear, date(2020, 4, 3)),
+        ]
+    );
+}
+
+#[test]
+fn unambiguous_strings() {
+    assert_eq!(
+        candidates(&Parser::default(), "March 4 2024 10:30"),
+        vec![(
+            DateOrder::MonthDayYear,
+            date(2024, 3, 4) + chrono::Duration::minutes(630)
+        )]
+    );
+
+    let parser = Parser::default();
+    let default = date(2020, 6, 15);
+    assert!(parser
+        .parse_candidates("2024-13-45", false, Some(&default), false, &HashMap::new())
+        .is_err());
+}
+
+#[test]
+fn ambiguous_date_errors() {
+    let parser = Parser::default().with_ambiguous_date_errors(true);
+    let parse = |timestr: &str, dayfirst: Option<bool>| {
+        parser
+            .parse(timestr, dayfirst, None, false, false, None, false, &HashMap::new())
+            .map(|res| res.0.date())
+    };
+
+    assert_eq!(
+        parse("03/04/2024", None),
+        Err(ParseError::AmbiguousDate(vec![
+            DateOrder::MonthDayYear,
+            DateOrder::DayMonthYear
+        ]))
+    );
+    assert_eq!(parse("03/04/2024", Some(true)), Ok(date(2024, 4, 3).date()));
+    assert_eq!(parse("25/04/2024", None), Ok(date(2024, 4, 25).date()));
+    assert_eq!(parse("04/04/2024", None), Ok(date(2024, 4, 4).date()));
+    assert_eq!(parse("Apr 3 2024", None), Ok(date(2024, 4, 3).date()));
+
+    let ordered = Parser::default()
+        .with_ambiguous_date_errors(true)
+        .with_date_order(DateOrder::DayMonthYear);
+    assert_eq!(
+        ordered
+            .parse("03/04/2024", None, None, false, false, None, false, &HashMap::new())
+            .map(|res| res.0.date()),
+        Ok(date(2024, 4, 3).date())
+    );
+}
+
+#[test]
+fn iso_dates_are_not_ambiguous() {
+    let parser = Parser::default().with_ambiguous_date_errors(true);
+    let parse = |parser: &Parser, timestr: &str| {
+        parser
+            .parse(timestr, None, None, false, false, None, false, &HashMap::new())
+            .map(|res| res.0)
+    };
+
+    assert_eq!(parse(&parser, "2024-03-05"), Ok(date(2024, 3, 5)));
+    assert_eq!(parse(&parser, "2024-03-05 10:00"), Ok(date(2024, 3, 5) + chrono::Duration::hours(10)));
+    assert_eq!(
+        parse(&parser, "2024.03.05"),
+        Err(ParseError::AmbiguousDate(vec![
+            DateOrder::YearMonthDay,
+            DateOrder::YearDayMonth
+        ]))
+    );
+    assert_eq!(
+        candidates(&Parser::default(), "2024-03-05"),
+        vec![(DateOrder::YearMonthDay, date(2024, 3, 5))]
+    );
+
+    let info = ParserInfo {
+        dayfirst: true,
+        ..Default::default()
+    };
+    let dayfirst = Parser::new(info).with_ambiguous_date_errors(true);
+    assert_eq!(parse(&dayfirst, "2024-03-05"), Ok(date(2024, 3, 5)));
+}
@@ -0,0 +1,216 @@
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use calendar::Persian;
+use column::{infer_date_order, parse_column, ColumnParser};
+use DateOrder;
+use ParseError;
+use Parser;
+use ParserInfo;
+
+fn assert_matches_parser(column: &mut ColumnParser, parser: &Parser, values: &[&str]) {
+    for value in values {
+        let expected = parser
+            .parse(value, None, None, false, false, None, false, &HashMap::new())
+            .map(|res| (res.0, res.1));
+        assert_eq!(column.parse(value), expected, "Mismatch for '{}'", value);
+    }
+}
+
+#[test]
+fn learns_layout() {
+    let mut column = ColumnParser::new(Parser::default(), 2);
+    let values = [
+        "2024-03-05 14:30:00.123456+01:00",
+        "2024-03-06 09:15:00.000001+01:00",
+        "2024-03-07 18:45:59.999999-05:30",
+        "2024-12-31 23:59:59.500000+00:00",
+    ];
+    assert_matches_parser(&mut column, &Parser::default(), &values);
+
+    assert_eq!(column.layout(), Some("%Y-%m-%d %H:%M:%S.%6f%:z"));
+    assert_eq!(column.fast_parsed(), 2);
+    assert_eq!(column.fallbacks(), 0);
+}
+
+#[test]
+fn falls_back_on_other_layouts() {
+    let mut column = ColumnParser::new(Parser::default(), 2);
+    let values = [
+        "Thu Sep 25 10:36:28 2003",
+        "Fri Sep 26 10:36:28 2003",
+        "Sat Sep 27 10:36:28 2003",
+        "September 28, 2003",
+        "Mon Sep 29 10:36:28 2003 UTC",
+    ];
+    assert_matches_parser(&mut column, &Parser::default(), &values);
+
+    assert_eq!(column.layout(), Some("%a %b %d %H:%M:%S %Y"));
+    assert_eq!(column.fast_parsed(), 1);
+    assert_eq!(column.fallbacks(), 2);
+}
+
+#[test]
+fn falls_back_when_date_order_differs() {
+    let mut column = ColumnParser::new(Parser::default(), 2);
+    // Learned as month-first, but the full parser reads "13/03/2024" day-first
+    let values = ["03/04/2024 10:00", "05/06/2024 11:00", "13/03/2024 12:00", "07/08/2024 1:00"];
+    assert_matches_parser(&mut column, &Parser::default(), &values);
+
+    assert_eq!(column.layout(), Some("%m/%d/%Y %H:%M"));
+    assert_eq!(column.fast_parsed(), 0);
+    assert_eq!(column.fallbacks(), 2);
+
+    let parser = || Parser::new(ParserInfo { dayfirst: true, ..ParserInfo::default() });
+    let mut column = ColumnParser::new(parser(), 1);
+    assert_matches_parser(&mut column, &parser(), &["03/04/2024", "25/12/2024", "05/06/2024"]);
+    assert_eq!(column.layout(), Some("%d/%m/%Y"));
+    assert_eq!(column.fast_parsed(), 2);
+}
+
+#[test]
+fn literal_timezones_and_ampm() {
+    let mut column = ColumnParser::new(Parser::default(), 2);
+    let values = ["3/5/24 2:30 PM UTC", "3/6/24 12:15 AM UTC", "12/25/24 11:59 PM UTC", "1/1/25 12:00 PM UTC"];
+    assert_matches_parser(&mut column, &Parser::default(), &values);
+
+    assert_eq!(column.layout(), Some("%-m/%-d/%y %-I:%M %p UTC"));
+    assert_eq!(column.fast_parsed(), 2);
+    assert_eq!(column.fallbacks(), 0);
+}
+
+#[test]
+fn validates_leap_seconds() {
+    let parser = || Parser::default().with_leap_second_validation(true);
+    let mut column = ColumnParser::new(parser(), 2);
+    let values = ["2015/03/05 23:59:59", "2015/03/06 23:59:59", "2015/03/07 23:59:60", "2015/06/30 23:59:60"];
+    assert_matches_parser(&mut column, &parser(), &values);
+
+    assert_eq!(column.layout(), Some("%Y/%m/%d %H:%M:%S"));
+    assert_eq!(column.fast_parsed(), 1);
+    assert_eq!(column.fallbacks(), 1);
+}
+
+#[test]
+fn keeps_calendar_options() {
+    let reform = NaiveDate::from_ymd_opt(1752, 9, 14).unwrap();
+    let parser = || Parser::default().with_julian_reform(reform);
+    let mut column = ColumnParser::new(parser(), 2);
+    assert_matches_parser(&mut column, &parser(), &["2024-03-05", "2024-03-06", "1700-03-05", "1752-09-02"]);
+    assert_eq!(column.fast_parsed(), 2);
+
+    let parser = || Parser::new(ParserInfo::default().with_thai_buddhist_years());
+    let mut column = ColumnParser::new(parser(), 2);
+    assert_matches_parser(&mut column, &parser(), &["05/03/24", "06/03/24", "07/03/2567", "08/03/24"]);
+
+    let parser = || Parser::default().with_calendar(Persian);
+    let mut column = ColumnParser::new(parser(), 2);
+    assert_matches_parser(&mut column, &parser(), &["1402/12/15", "1402/12/16", "02/12/17", "1403/01/01"]);
+}
+
+#[test]
+fn falls_back_on_multibyte_offsets() {
+    let mut column = ColumnParser::new(Parser::default(), 2);
+    let values = [
+        "2024-03-05 14:30 +05:30",
+        "2024-03-06 14:30 +05:30",
+        "2024-03-07 14:30 +0é:00",
+        "2024-03-07 14:30 +05é00",
+        "2024-03-08 14:30 +05:30",
+    ];
+    assert_matches_parser(&mut column, &Parser::default(), &values);
+
+    assert_eq!(column.layout(), Some("%Y-%m-%d %H:%M %:z"));
+    assert_eq!(column.fast_parsed(), 1);
+    assert_eq!(column.fallbacks(), 2);
+}
+
+#[test]
+fn refuses_ambiguous_dates() {
+    let parser = || {
+        Parser::new(ParserInfo { dayfirst: true, ..ParserInfo::default() }).with_ambiguous_date_errors(true)
+    };
+    let mut column = ColumnParser::new(parser(), 2);
+    let values = ["25/04/2024 10:00", "26/04/2024 10:00", "03/04/2024 10:00", "27/04/2024 10:00"];
+    assert_matches_parser(&mut column, &parser(), &values);
+    assert!(column.parse("03/04/2024 10:00").is_err());
+
+    assert_eq!(column.layout(), Some("%d/%m/%Y %H:%M"));
+    assert_eq!(column.fast_parsed(), 1);
+}
+
+#[test]
+fn disagreeing_samples() {
+    let mut column = ColumnParser::new(Parser::default(), 2);
+    assert_matches_parser(&mut column, &Parser::default(), &["2024-03-05", "March 5, 2024", "2024-03-06"]);
+
+    assert_eq!(column.layout(), None);
+    assert_eq!(column.fallbacks(), 1);
+}
+
+#[test]
+fn infers_day_first_column() {
+    let values = ["03/04/2024", "25/04/2024", "Apr 26 2024", "01/05/2024"];
+    let (order, parsed) = parse_column(Parser::default(), 2, &values).unwrap();
+    assert_eq!(order, DateOrder::DayMonthYear);
+
+    let dates: Vec<NaiveDate> = parsed.into_iter().map(|r| r.unwrap().0.date()).collect();
+    assert_eq!(
+        dates,
+        vec![
+            NaiveDate::from_ymd_opt(2024, 4, 3).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 25).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 4, 26).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn ambiguous_column_keeps_parser_order() {
+    let values = ["03/04/2024", "05/06/2024"];
+    assert_eq!(
+        infer_date_order(&Parser::default(), &values),
+        Ok(DateOrder::MonthDayYear)
+    );
+
+    let info = ParserInfo {
+        dayfirst: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        infer_date_order(&Parser::new(info), &values),
+        Ok(DateOrder::DayMonthYear)
+    );
+}
+
+#[test]
+fn infers_year_first_orders() {
+    let parser = Parser::default();
+    assert_eq!(
+        infer_date_order(&parser, &["2024.03.04", "2024.03.25"]),
+        Ok(DateOrder::YearMonthDay)
+    );
+    assert_eq!(
+        infer_date_order(&parser, &["2024.03.04", "2024.25.03"]),
+        Ok(DateOrder::YearDayMonth)
+    );
+}
+
+#[test]
+fn rejects_inconsistent_column() {
+    let values = ["03/04/2024", "25/04/2024", "04/26/2024"];
+    assert_eq!(
+        parse_column(Parser::default(), 2, &values).map(|r| r.0),
+        Err(ParseError::InconsistentDateOrder("04/26/2024".to_owned()))
+    );
+}
+
+#[test]
+fn ignores_unparseable_values() {
+    let values = ["not a date", "13/01/2024", ""];
+    let (order, parsed) = parse_column(Parser::default(), 2, &values).unwrap();
+    assert_eq!(order, DateOrder::DayMonthYear);
+    assert!(parsed[0].is_err());
+    assert_eq!(parsed[1].as_ref().unwrap().0.date(), NaiveDate::from_ymd_opt(2024, 1, 13).unwrap());
+}
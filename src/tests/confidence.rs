@@ -0,0 +1,90 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use ParseError;
+use Parser;
+use ScoredParse;
+use TimestampMode;
+use TimestampUnit;
+
+fn scored(parser: &Parser, timestr: &str) -> ScoredParse {
+    let default = NaiveDate::from_ymd_opt(2020, 6, 15).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    parser
+        .parse_scored(timestr, None, None, Some(&default), false, &HashMap::new())
+        .unwrap()
+}
+
+fn assert_confidence(timestr: &str, expected: f64) {
+    let confidence = scored(&Parser::default(), timestr).confidence;
+    assert!(
+        (confidence - expected).abs() < 1e-9,
+        "Confidence for '{}' was {}, expected {}",
+        timestr,
+        confidence,
+        expected
+    );
+}
+
+#[test]
+fn complete_timestamps() {
+    assert_confidence("Sep 25 2003 10:49", 1.0);
+    assert_confidence("2003-09-25T10:49:41", 0.85);
+    assert_confidence("Thursday, 25 September 2003", 0.95);
+}
+
+#[test]
+fn stray_numbers() {
+    assert_confidence("Room 12, floor 5", 0.4);
+    assert_confidence("I have 3 apples and 4 pears", 0.4 * 2.0 / 6.0 + 0.2);
+
+    let res = scored(&Parser::default(), "Room 12, floor 5");
+    assert_eq!(
+        res.datetime,
+        NaiveDate::from_ymd_opt(2020, 12, 5).unwrap().and_hms_opt(0, 0, 0).unwrap()
+    );
+    assert_eq!(res.skipped_tokens, vec!["Room ", " floor "]);
+}
+
+#[test]
+fn mixed_separators() {
+    assert_confidence("25/09/2003", 0.8);
+    assert_confidence("25/09-2003", 0.7);
+}
+
+#[test]
+fn skipped_words() {
+    assert_confidence(
+        "Today is 25 of September of 2003, exactly at 10:49:41 with timezone -03:00.",
+        0.4 * 8.0 / 13.0 + 0.6,
+    );
+}
+
+#[test]
+fn timestamps_score_one() {
+    let parser = Parser::default().with_timestamps(TimestampMode::Unit(TimestampUnit::Seconds));
+    assert_eq!(scored(&parser, "1064486981").confidence, 1.0);
+}
+
+#[test]
+fn threshold() {
+    let parser = Parser::default().with_min_confidence(0.5);
+    let parse = |timestr: &str, fuzzy: bool| -> Result<NaiveDateTime, ParseError> {
+        parser
+            .parse(timestr, None, None, fuzzy, false, None, false, &HashMap::new())
+            .map(|res| res.0)
+    };
+
+    match parse("Room 12, floor 5", true) {
+        Err(ParseError::LowConfidence(c)) => assert!((c - 0.4).abs() < 1e-9),
+        other => panic!("Expected LowConfidence, got {:?}", other),
+    }
+    assert!(parse("Meet me on Sep 25 2003 at 10:49", true).is_ok());
+    // Only fuzzy parses are held to the threshold
+    assert!(parse("12/5", false).is_ok());
+
+    assert!(matches!(
+        parser.parse_scored("Room 12, floor 5", None, None, None, false, &HashMap::new()),
+        Err(ParseError::LowConfidence(_))
+    ));
+}
@@ -0,0 +1,107 @@
+use chrono::Datelike;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Weekday;
+use std::collections::HashMap;
+
+use parse;
+use parse_info;
+use ParseError;
+use Parser;
+use ParserInfo;
+
+fn midnight(y: i32, m: u32, d: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+}
+
+fn parse_date(timestr: &str) -> Result<NaiveDateTime, ParseError> {
+    parse(timestr).map(|res| res.0)
+}
+
+#[test]
+fn astronomical_years() {
+    assert_eq!(parse_date("15 March 44 BC"), Ok(midnight(-43, 3, 15)));
+    assert_eq!(parse_date("March 15, 44 B.C."), Ok(midnight(-43, 3, 15)));
+    assert_eq!(parse_date("21 April 753 BCE"), Ok(midnight(-752, 4, 21)));
+    assert_eq!(parse_date("Jan 1 1 BC"), Ok(midnight(0, 1, 1)));
+    assert_eq!(parse_date("Jan 1 1 AD"), Ok(midnight(1, 1, 1)));
+    assert_eq!(parse_date("14 October A.D. 1066"), Ok(midnight(1066, 10, 14)));
+    assert_eq!(parse_date("24 August 79 CE"), Ok(midnight(79, 8, 24)));
+    assert_eq!(
+        parse_date("1996.07.10 AD 12:08"),
+        Ok(NaiveDate::from_ymd_opt(1996, 7, 10).unwrap().and_hms_opt(12, 8, 0).unwrap())
+    );
+}
+
+#[test]
+fn era_marks_the_year() {
+    // A lone 10 would be the day of the month
+    let default = midnight(2024, 3, 5);
+    let parsed = Parser::default()
+        .parse("10 BC", None, None, false, false, Some(&default), false, &HashMap::new())
+        .unwrap();
+    assert_eq!(parsed.0, midnight(-9, 3, 5));
+}
+
+#[test]
+fn invalid_eras() {
+    assert_eq!(parse_date("0 BC"), Err(ParseError::ImpossibleTimestamp("Invalid year")));
+    assert_eq!(
+        parse_date("March BC"),
+        Err(ParseError::ImpossibleTimestamp("Era without a year"))
+    );
+}
+
+#[test]
+fn calendar_before_year_one() {
+    // 1 BC and 401 BC are leap years, 101 BC isn't
+    assert_eq!(parse_date("Feb 29 1 BC"), Ok(midnight(0, 2, 29)));
+    assert_eq!(parse_date("Feb 29 401 BC"), Ok(midnight(-400, 2, 29)));
+    assert_eq!(parse_date("Feb 29 101 BC"), Ok(midnight(-100, 2, 28)));
+    assert_eq!(parse_date("Feb 29 2 BC"), Ok(midnight(-1, 2, 28)));
+
+    let default = midnight(2024, 3, 1);
+    let parsed = Parser::default()
+        .parse("Monday, March 44 BC", None, None, false, false, Some(&default), false, &HashMap::new())
+        .unwrap();
+    assert_eq!(parsed.0.weekday(), Weekday::Mon);
+    assert_eq!((parsed.0.year(), parsed.0.month()), (-43, 3));
+    assert!(parsed.0.day() <= 7);
+}
+
+#[test]
+fn localized_eras() {
+    let info = ParserInfo {
+        months: parse_info(vec![
+            vec!["janv", "janvier"],
+            vec!["févr", "février"],
+            vec!["mars"],
+        ]),
+        era: parse_info(vec![vec!["av. J.-C.", "avant Jésus-Christ"], vec!["ap. J.-C."]]),
+        ..ParserInfo::default()
+    };
+    let parser = Parser::new(info);
+    let parse_fr = |timestr| {
+        parser
+            .parse(timestr, None, None, false, false, None, false, &HashMap::new())
+            .map(|res| res.0)
+    };
+    assert_eq!(parse_fr("15 mars 44 av. J.-C."), Ok(midnight(-43, 3, 15)));
+    assert_eq!(parse_fr("15 mars 44 avant Jésus-Christ"), Ok(midnight(-43, 3, 15)));
+    assert_eq!(parse_fr("1 janvier 14 ap. J.-C."), Ok(midnight(14, 1, 1)));
+    assert!(parse_fr("15 mars 44 BC").is_err());
+
+    let info = ParserInfo {
+        era: parse_info(vec![vec!["до н. э.", "до нашей эры"], vec!["н. э.", "нашей эры"]]),
+        ..ParserInfo::default()
+    };
+    let parser = Parser::new(info);
+    let parse_ru = |timestr| {
+        parser
+            .parse(timestr, None, None, false, false, None, false, &HashMap::new())
+            .map(|res| res.0)
+    };
+    assert_eq!(parse_ru("March 15 44 до н. э."), Ok(midnight(-43, 3, 15)));
+    assert_eq!(parse_ru("March 15 44 до нашей эры"), Ok(midnight(-43, 3, 15)));
+    assert_eq!(parse_ru("March 15 44 н. э."), Ok(midnight(44, 3, 15)));
+}
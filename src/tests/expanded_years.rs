@@ -0,0 +1,73 @@
+use chrono::Datelike;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use parse;
+use ParseError;
+use ParseResult;
+use Parser;
+
+fn midnight(y: i32, m: u32, d: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+}
+
+fn parse_digits(digits: usize, timestr: &str) -> ParseResult<NaiveDateTime> {
+    let parser = Parser::default().with_year_digits(digits);
+    let res = parser.parse(timestr, None, None, false, false, None, false, &HashMap::new())?;
+    Ok(res.0)
+}
+
+#[test]
+fn signed_years() {
+    assert_eq!(parse("+002024-03-05"), Ok((midnight(2024, 3, 5), None)));
+    assert_eq!(parse("+2024-03-05"), Ok((midnight(2024, 3, 5), None)));
+    assert_eq!(parse("-000044-03-15"), Ok((midnight(-44, 3, 15), None)));
+    assert_eq!(parse("+000000-01-01"), Ok((midnight(0, 1, 1), None)));
+    assert_eq!(
+        parse("+12345-01-01T10:00Z"),
+        Ok((
+            NaiveDate::from_ymd_opt(12345, 1, 1).unwrap().and_hms_opt(10, 0, 0).unwrap(),
+            FixedOffset::east_opt(0)
+        ))
+    );
+
+    // Two-digit years after a dash are still read as before
+    assert_eq!(parse("-44-03-15"), Ok((midnight(2044, 3, 15), None)));
+}
+
+#[test]
+fn five_digit_years() {
+    assert_eq!(parse("12345-01-01"), Ok((midnight(12345, 1, 1), None)));
+    assert_eq!(parse("Jan 1 12345"), Ok((midnight(12345, 1, 1), None)));
+    assert_eq!(parse("99999-12-31"), Ok((midnight(99999, 12, 31), None)));
+}
+
+#[test]
+fn configured_digits() {
+    assert_eq!(parse_digits(6, "012345-01-01"), Ok(midnight(12345, 1, 1)));
+    assert_eq!(parse_digits(6, "100000/03/05"), Ok(midnight(100_000, 3, 5)));
+    assert_eq!(parse_digits(6, "+002024-03-05"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_digits(6, "-000044-03-15"), Ok(midnight(-44, 3, 15)));
+    assert!(parse_digits(6, "+2024-03-05").is_err());
+
+    // A signed number on its own isn't a year
+    assert_eq!(parse("-819484"), Err(ParseError::ImpossibleTimestamp("Invalid month")));
+
+    // Without it, six digits are a two-digit year, month and day in some order
+    assert_eq!(parse("240305"), Ok((midnight(2005, 3, 24), None)));
+    assert_eq!(parse_digits(6, "240305").map(|dt| dt.year()), Ok(240_305));
+}
+
+#[test]
+fn out_of_range() {
+    let out_of_range = Err(ParseError::ImpossibleTimestamp("Year out of range"));
+    assert_eq!(parse("+300000-01-01").map(|res| res.0), out_of_range);
+    assert_eq!(parse("-300000-01-01").map(|res| res.0), out_of_range);
+    assert_eq!(parse("123456789-01-01").map(|res| res.0), out_of_range);
+    assert_eq!(parse("+99999999999999999999-01-01").map(|res| res.0), out_of_range);
+    assert_eq!(parse_digits(7, "0300000-01-01"), out_of_range);
+    assert_eq!(parse_digits(6, "262142-12-31"), Ok(midnight(262_142, 12, 31)));
+    assert_eq!(parse_digits(6, "262143-01-01"), out_of_range);
+}
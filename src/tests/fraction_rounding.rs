@@ -0,0 +1,133 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use FractionRounding;
+use ParseError;
+use ParseResult;
+use Parser;
+
+fn parse_rounded(rounding: FractionRounding, timestr: &str) -> ParseResult<NaiveDateTime> {
+    let parser = Parser::default().with_fraction_rounding(rounding);
+    let res = parser.parse(timestr, None, None, false, false, None, false, &HashMap::new())?;
+    Ok(res.0)
+}
+
+fn at(y: i32, m: u32, d: u32, h: u32, min: u32, s: u32, nano: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_nano_opt(h, min, s, nano)
+        .unwrap()
+}
+
+#[test]
+fn truncates_by_default() {
+    let parsed = parse_rounded(FractionRounding::default(), "2024-03-05 10:00:00.9999999999");
+    assert_eq!(parsed, Ok(at(2024, 3, 5, 10, 0, 0, 999_999_999)));
+}
+
+#[test]
+fn half_up() {
+    let rounded = |timestr| parse_rounded(FractionRounding::HalfUp, timestr);
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.1234567894"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_456_789))
+    );
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.1234567885"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_456_789))
+    );
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.1234567895"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_456_790))
+    );
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.123"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_000_000))
+    );
+}
+
+#[test]
+fn half_even() {
+    let rounded = |timestr| parse_rounded(FractionRounding::HalfEven, timestr);
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.1234567885"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_456_788))
+    );
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.1234567895"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_456_790))
+    );
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.12345678850001"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_456_789))
+    );
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.1234567886"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_456_789))
+    );
+}
+
+#[test]
+fn carries_into_larger_fields() {
+    let rounded = |timestr| parse_rounded(FractionRounding::HalfUp, timestr);
+    assert_eq!(
+        rounded("2024-03-05 10:00:00.9999999999"),
+        Ok(at(2024, 3, 5, 10, 0, 1, 0))
+    );
+    assert_eq!(
+        rounded("2024-03-05 10:59:59.9999999995"),
+        Ok(at(2024, 3, 5, 11, 0, 0, 0))
+    );
+    assert_eq!(
+        rounded("1999-12-31 23:59:59.99999999999"),
+        Ok(at(2000, 1, 1, 0, 0, 0, 0))
+    );
+    assert_eq!(
+        rounded("2016-12-31 23:59:60.9999999999"),
+        Ok(at(2017, 1, 1, 0, 0, 0, 0))
+    );
+    assert_eq!(rounded("19991231T235959.9999999999"), Ok(at(2000, 1, 1, 0, 0, 0, 0)));
+    assert_eq!(rounded("Dec 31 1999 59.9999999999s"), Ok(at(1999, 12, 31, 0, 1, 0, 0)));
+}
+
+#[test]
+fn reject() {
+    let rejected = |timestr| parse_rounded(FractionRounding::Reject, timestr);
+    assert_eq!(
+        rejected("2024-03-05 10:00:00.123456789"),
+        Ok(at(2024, 3, 5, 10, 0, 0, 123_456_789))
+    );
+    assert_eq!(
+        rejected("2024-03-05 10:00:00.1234567890"),
+        Err(ParseError::ImpossibleTimestamp("Fractional seconds finer than nanoseconds"))
+    );
+}
+
+#[test]
+fn reports_fraction_digits() {
+    let digits = |timestr| {
+        Parser::default()
+            .parse_scored(timestr, None, None, None, false, &HashMap::new())
+            .unwrap()
+            .fraction_digits
+    };
+    assert_eq!(digits("2024-03-05 10:00:00"), None);
+    assert_eq!(digits("2024-03-05 10:00:00.5"), Some(1));
+    assert_eq!(digits("2024-03-05 10:00:00.500"), Some(3));
+    assert_eq!(digits("2024-03-05T10:00:00.123456789012Z"), Some(12));
+    assert_eq!(digits("20240305T100000.25"), Some(2));
+}
+
+#[test]
+fn reports_fraction_digits_strictly() {
+    let detailed = |timestr| {
+        Parser::default().parse_detailed(timestr, None, None, None, false, &HashMap::new())
+    };
+    assert_eq!(detailed("2024-03-05 10:00:00.500").unwrap().fraction_digits, Some(3));
+    assert_eq!(detailed("2024-03-05 10:00:00.500").unwrap().skipped_tokens, Vec::<String>::new());
+    assert_eq!(
+        detailed("Logged at 2024-03-05 10:00:00.500"),
+        Err(ParseError::UnrecognizedToken("Logged".to_owned()))
+    );
+}
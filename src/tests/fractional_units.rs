@@ -0,0 +1,77 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use parse;
+use FractionRounding;
+use ParseError;
+use ParseResult;
+use Parser;
+
+fn at(h: u32, min: u32, s: u32, nano: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2003, 9, 25)
+        .unwrap()
+        .and_hms_nano_opt(h, min, s, nano)
+        .unwrap()
+}
+
+fn parse_rounded(rounding: FractionRounding, timestr: &str) -> ParseResult<NaiveDateTime> {
+    let parser = Parser::default().with_fraction_rounding(rounding);
+    let res = parser.parse(timestr, None, None, false, false, None, false, &HashMap::new())?;
+    Ok(res.0)
+}
+
+#[test]
+fn matches_dateutil_when_exact() {
+    // Fractions that come to whole minutes or seconds give what dateutil does
+    assert_eq!(parse("Sep 25 2003 10.5h"), Ok((at(10, 30, 0, 0), None)));
+    assert_eq!(parse("Sep 25 2003 10h 36.25m"), Ok((at(10, 36, 15, 0), None)));
+    assert_eq!(parse("Sep 25 2003 10:30.5"), Ok((at(10, 30, 30, 0), None)));
+}
+
+#[test]
+fn fractional_hours() {
+    // dateutil can't read these at all; "1.2345" was taken for HHMMSS
+    assert_eq!(parse("Sep 25 2003 1.2345h"), Ok((at(1, 14, 4, 200_000_000), None)));
+    assert_eq!(parse("Sep 25 2003 12.345 h"), Ok((at(12, 20, 42, 0), None)));
+    assert_eq!(
+        parse("Sep 25 2003 10.12345678h"),
+        Ok((at(10, 7, 24, 444_408_000), None))
+    );
+}
+
+#[test]
+fn fractional_minutes() {
+    // dateutil gives 00:12:20 and 10:12:20
+    assert_eq!(parse("Sep 25 2003 12.3456m"), Ok((at(0, 12, 20, 736_000_000), None)));
+    assert_eq!(parse("Sep 25 2003 10:12.3456"), Ok((at(10, 12, 20, 736_000_000), None)));
+    assert_eq!(
+        parse("Sep 25 2003 10h 12.3456m"),
+        Ok((at(10, 12, 20, 736_000_000), None))
+    );
+}
+
+#[test]
+fn rounds_past_nanoseconds() {
+    // 0.0000000001 of a minute is 6 nanoseconds; a tenth of that is 0.6
+    let timestr = "Sep 25 2003 10:12.00000000001";
+    assert_eq!(parse_rounded(FractionRounding::Truncate, timestr), Ok(at(10, 12, 0, 0)));
+    assert_eq!(parse_rounded(FractionRounding::HalfUp, timestr), Ok(at(10, 12, 0, 1)));
+    assert_eq!(parse_rounded(FractionRounding::HalfEven, timestr), Ok(at(10, 12, 0, 1)));
+    assert_eq!(
+        parse_rounded(FractionRounding::Reject, timestr),
+        Err(ParseError::ImpossibleTimestamp("Fractional seconds finer than nanoseconds"))
+    );
+
+    // 1.25e-12 of an hour is 4.5 nanoseconds
+    let timestr = "Sep 25 2003 10.00000000000125h";
+    assert_eq!(parse_rounded(FractionRounding::HalfUp, timestr), Ok(at(10, 0, 0, 5)));
+    assert_eq!(parse_rounded(FractionRounding::HalfEven, timestr), Ok(at(10, 0, 0, 4)));
+
+    let timestr = "Sep 25 2003 10.99999999999999h";
+    assert_eq!(
+        parse_rounded(FractionRounding::Truncate, timestr),
+        Ok(at(10, 59, 59, 999_999_999))
+    );
+    assert_eq!(parse_rounded(FractionRounding::HalfUp, timestr), Ok(at(11, 0, 0, 0)));
+}
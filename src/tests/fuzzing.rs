@@ -2,11 +2,21 @@ use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
 use chrono::NaiveDate;
 use std::collections::HashMap;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample;
 use std::str;
 
+use calendar::Hebrew;
+use column::infer_date_order;
+use column::ColumnParser;
 use parse;
+use FractionRounding;
+use Limits;
 use ParseError;
 use Parser;
+use ParserInfo;
+use TimestampMode;
 
 #[test]
 fn test_fuzz() {
@@ -15,10 +25,11 @@ fn test_fuzz() {
         Err(ParseError::ImpossibleTimestamp("Invalid month"))
     );
 
-    // Garbage in the third delimited field
+    // Garbage in the third delimited field; like dateutil, the tokenizer
+    // doesn't produce an empty token between the two dots
     assert_eq!(
         parse("2..\x00\x000d\x00+\x010d\x01\x00\x00\x00+"),
-        Err(ParseError::UnrecognizedFormat)
+        Err(ParseError::UnrecognizedToken("\x00".to_owned()))
     );
 
     let default = NaiveDate::from_ymd_opt(2016, 6, 29).unwrap().and_hms_opt(0, 0, 0).unwrap();
@@ -59,6 +70,14 @@ fn an_even_larger_int() {
     assert!(parse_result.is_err());
 }
 
+#[test]
+fn ints_past_i32() {
+    assert_eq!(parse("4294967306:00"), Err(ParseError::InvalidNumeric("4294967306".to_owned())));
+    assert!(parse("10:4294967306").is_err());
+    assert!(parse("4294967306 pm").is_err());
+    assert!(parse("Jan 4294967306").is_err());
+}
+
 #[test]
 fn empty_string() {
     assert_eq!(parse(""), Err(ParseError::NoDate))
@@ -103,4 +122,165 @@ fn github_45() {
     assert!(parse("/2018-fifa-").is_err());
     assert!(parse("/2009/07/").is_err());
     assert!(parse("2021-09-").is_err());
-}
\ No newline at end of file
+}
+/// Valid strings in a range of formats, for the mutation tests to corrupt
+const SEEDS: &[&str] = &[
+    "Thu Sep 25 10:36:28 BRST 2003",
+    "2003-09-25T10:49:41.5-03:00",
+    "2003-09-25 10:49:41,502",
+    "10-09-2003",
+    "Sep-25-2003",
+    "25 of September 2003",
+    "Jan of 01",
+    "10h36m28.5s",
+    "5:50 A.M. on June 13, 1990",
+    "1990101T32",
+    "19990101T2359",
+    "20030925T104941-0300",
+    "12:08 PM GMT+3",
+    "2003-09-25T10:49:41 -03:00 (BRST)",
+    "JD 2460374.5",
+    "2016-12-31T23:59:60.5+01:00",
+    "1999-12-31 23:59:59.99999999995",
+    "Sep 25 2003 1.2345h 12.3456m",
+    "Friday, 15 March 44 B. C.",
+    "+012345-01-01T10:00:00Z",
+    "Tue, 10 February 1709/10 O.S. 12:00",
+    "令和元年5月1日 R6.3.5",
+    "民國113年3月5日 BE 2567",
+    "24 Adar II 5784, 15 Sha'ban 1445",
+    "1412409095009",
+    "@1412409095.25",
+    "3rd of May 2001",
+    "0:01:02 on July 4, 1976",
+    "99999999999999999999999.9999",
+];
+
+/// Call every public entry point, which must return rather than panic
+fn parse_everything(timestr: &str) {
+    let tzinfos = HashMap::new();
+    let _ = parse(timestr);
+
+    let parser = Parser::default();
+    for &(fuzzy, fuzzy_with_tokens) in &[(false, false), (true, false), (true, true)] {
+        for &dayfirst in &[None, Some(true)] {
+            for &yearfirst in &[None, Some(true)] {
+                let _ = parser.parse(timestr, dayfirst, yearfirst, fuzzy, fuzzy_with_tokens, None, false, &tzinfos);
+            }
+        }
+    }
+    let _ = parser.parse_scored(timestr, None, None, None, false, &tzinfos);
+    let _ = parser.parse_candidates(timestr, false, None, false, &tzinfos);
+    let _ = parser.infer_format(timestr);
+
+    let timestamps = Parser::default().with_timestamps(TimestampMode::default());
+    let _ = timestamps.parse(timestr, None, None, false, false, None, false, &tzinfos);
+
+    let strict = Parser::default()
+        .with_ambiguous_date_errors(true)
+        .with_min_confidence(0.5)
+        .with_leap_second_validation(true)
+        .with_fraction_rounding(FractionRounding::Reject)
+        .with_julian_reform(NaiveDate::from_ymd_opt(1752, 9, 14).unwrap());
+    let _ = strict.parse(timestr, None, None, true, false, None, false, &tzinfos);
+
+    let rounding = Parser::default().with_fraction_rounding(FractionRounding::HalfEven);
+    let _ = rounding.parse(timestr, None, None, true, false, None, false, &tzinfos);
+
+    let calendars = Parser::new(ParserInfo::default().with_japanese_eras().with_minguo_years());
+    let _ = calendars.parse(timestr, None, None, true, false, None, false, &tzinfos);
+
+    let hebrew = Parser::default().with_calendar(Hebrew);
+    let _ = hebrew.parse(timestr, None, None, true, false, None, false, &tzinfos);
+
+    let limited = Parser::default().with_limits(Limits {
+        max_bytes: Some(30),
+        max_tokens: Some(12),
+        max_digits: Some(8),
+        max_skipped_tokens: Some(2),
+    });
+    let _ = limited.parse(timestr, None, None, true, false, None, false, &tzinfos);
+
+    let _ = infer_date_order(&Parser::default(), &[timestr, timestr]);
+    let mut column = ColumnParser::new(Parser::default(), 1);
+    let _ = column.parse(timestr);
+    let _ = column.parse(timestr);
+
+    // Learn a layout from valid values first, so the string reaches the
+    // learned-layout fast path
+    let layouts = [
+        ["2024-03-05 14:30 +05:30", "2024-03-06 09:15 -01:00"],
+        ["5 Mar 2024 2:30 PM +0530", "6 Mar 2024 9:15 AM -0100"],
+    ];
+    for samples in &layouts {
+        let mut column = ColumnParser::new(Parser::default(), 2);
+        for sample in samples {
+            let _ = column.parse(sample);
+        }
+        assert!(column.layout().is_some());
+        let _ = column.parse(timestr);
+    }
+}
+
+/// Apply each edit to `seed`: an index into it, a character, and whether to
+/// insert, replace or delete there
+fn mutate(seed: &str, edits: &[(usize, char, u8)]) -> String {
+    let mut chars: Vec<char> = seed.chars().collect();
+    for &(idx, c, op) in edits {
+        let idx = idx % (chars.len() + 1);
+        match op {
+            0 => chars.insert(idx, c),
+            1 if idx < chars.len() => chars[idx] = c,
+            _ if idx < chars.len() => {
+                chars.remove(idx);
+            }
+            _ => {}
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[test]
+fn former_panics() {
+    for timestr in &[
+        "Jan-",
+        "Jan-01-",
+        "10:00 +01:",
+        "10:00 +9999999:00",
+        "10:00 +é1",
+        "10:00:1.éééééééééé",
+        "10:00:99999999999.5",
+        "3 99999999999.5s",
+        "Monday 0000-01",
+        "10:00:00 Mon 262143-12",
+        "2024-03-07 14:30 +0é:00",
+        "7 Mar 2024 2:30 PM +05é0",
+    ] {
+        parse_everything(timestr);
+    }
+    assert_eq!(parse("10:00 +9999999:00"), Err(ParseError::ImpossibleTimestamp("Invalid offset")));
+}
+
+proptest! {
+    #[test]
+    fn never_panics_on_any_string(timestr in any::<String>()) {
+        parse_everything(&timestr);
+    }
+
+    #[test]
+    fn never_panics_on_date_like_strings(timestr in "[0-9a-zA-Z :.,/+\\-()'@]{0,40}") {
+        parse_everything(&timestr);
+    }
+
+    #[test]
+    fn never_panics_on_mutated_dates(
+        seed in sample::select(SEEDS),
+        edits in vec((any::<usize>(), "[0-9a-zA-Z :.,/+\\-]", 0..3u8), 1..6),
+    ) {
+        let edits: Vec<_> = edits
+            .into_iter()
+            .map(|(idx, c, op)| (idx, c.chars().next().unwrap(), op))
+            .collect();
+        parse_everything(&mutate(seed, &edits));
+    }
+}
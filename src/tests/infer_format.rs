@@ -0,0 +1,79 @@
+use chrono::TimeZone;
+use std::collections::HashMap;
+
+use ParseError;
+use Parser;
+use ParserInfo;
+
+fn assert_round_trip(p: &Parser, s: &str, expected: &str) {
+    let pattern = p.infer_format(s).unwrap_or_else(|e| panic!("No pattern for '{}': {:?}", s, e));
+    assert_eq!(pattern, expected, "Pattern mismatch for '{}'", s);
+
+    let (naive, offset, _) = p
+        .parse(s, None, None, false, false, None, false, &HashMap::new())
+        .unwrap();
+    let formatted = match offset {
+        Some(offset) => offset.from_local_datetime(&naive).unwrap().format(&pattern).to_string(),
+        None => naive.format(&pattern).to_string(),
+    };
+    assert_eq!(formatted, s, "Round trip mismatch for '{}'", s);
+}
+
+#[test]
+fn infer_iso_formats() {
+    let p = Parser::default();
+    assert_round_trip(&p, "2024-03-05T14:30:00.123456Z", "%Y-%m-%dT%H:%M:%S.%6fZ");
+    assert_round_trip(&p, "2024-03-05 14:30:00+01:00", "%Y-%m-%d %H:%M:%S%:z");
+    assert_round_trip(&p, "2024-03-05 14:30:00 -0500", "%Y-%m-%d %H:%M:%S %z");
+    assert_round_trip(&p, "20240305", "%Y%m%d");
+    assert_round_trip(&p, "20240305T143000", "%Y%m%dT%H%M%S");
+    assert_round_trip(&p, "2003-09-25 10:49:41,502", "%Y-%m-%d %H:%M:%S,%3f");
+    assert_round_trip(&p, "2003-09-25\t10:49", "%Y-%m-%d\t%H:%M");
+}
+
+#[test]
+fn infer_human_formats() {
+    let p = Parser::default();
+    assert_round_trip(&p, "Thu Sep 25 10:36:28 2003", "%a %b %d %H:%M:%S %Y");
+    assert_round_trip(&p, "Thursday, September 25, 2003", "%A, %B %d, %Y");
+    assert_round_trip(&p, "3/5/24 2:30 PM", "%-m/%-d/%y %-I:%M %p");
+    assert_round_trip(&p, "10am", "%I%P");
+    assert_round_trip(&p, "25/03/2024 14:30", "%d/%m/%Y %H:%M");
+}
+
+#[test]
+fn infer_respects_dayfirst() {
+    let info = ParserInfo {
+        dayfirst: true,
+        ..ParserInfo::default()
+    };
+    assert_round_trip(&Parser::new(info), "05/03/2024 14:30", "%d/%m/%Y %H:%M");
+    assert_round_trip(&Parser::default(), "05/03/2024 14:30", "%m/%d/%Y %H:%M");
+}
+
+#[test]
+fn infer_escapes_literals() {
+    let p = Parser::new(ParserInfo {
+        jump: ::parse_info(vec![vec![" ", "%", "-", ":"]]),
+        ..ParserInfo::default()
+    });
+    assert_round_trip(&p, "2024-03-05 % 14:30", "%Y-%m-%d %% %H:%M");
+}
+
+#[test]
+fn no_pattern() {
+    let p = Parser::default();
+    assert_eq!(
+        p.infer_format("10.5h"),
+        Err(ParseError::NoFormatPattern("Token has no strftime equivalent"))
+    );
+    assert_eq!(
+        p.infer_format("SEP 25 2003"),
+        Err(ParseError::NoFormatPattern("Pattern doesn't reproduce the input"))
+    );
+    assert_eq!(
+        p.infer_format("2003-09-25 10:36:28.12"),
+        Err(ParseError::NoFormatPattern("Fractional seconds must have 3, 6 or 9 digits"))
+    );
+    assert_eq!(p.infer_format(""), Err(ParseError::NoDate));
+}
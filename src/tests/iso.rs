@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use iso;
+use Parser;
+
+const INPUTS: &[&str] = &[
+    "2024-03-05",
+    "2024-03-05T14:30",
+    "2024-03-05 14:30:00",
+    "2024-03-05T14:30:00.123456Z",
+    "2024-03-05T14:30:00.123456789z",
+    "2024-03-05T14:30:00,5",
+    "2024-03-05 14:30:00+01:00",
+    "2024-03-05T14:30:00-05:30",
+    "2024-03-05T14:30:00+0130",
+    "2024-03-05T14:30:00-05",
+    "2024-03-05T14:30:00-00:00",
+    "2024-03-05T14:30:00+25:00",
+    "2024-03-05T14:30:00+01:75",
+    "2024-12-31T23:59:59.999999999+14:00",
+    "0000-01-01",
+    "0099-01-01T00:00Z",
+    "2024-02-29T12:00:00",
+];
+
+/// `Parser::parse` without the fast path
+fn general(
+    parser: &Parser,
+    timestr: &str,
+    ignoretz: bool,
+) -> (NaiveDateTime, Option<chrono::FixedOffset>) {
+    let default = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let (res, _) = parser.parse_with_tokens(timestr, None, None, false, false).unwrap();
+    parser.build_result(&res, &default, ignoretz, &HashMap::new()).unwrap()
+}
+
+#[test]
+fn matches_general_parser() {
+    let parser = Parser::default();
+    for timestr in INPUTS {
+        assert!(iso::parse(timestr).is_some(), "Fast path skipped '{}'", timestr);
+
+        for &ignoretz in &[false, true] {
+            let (naive, offset, tokens) = parser
+                .parse(timestr, None, None, false, false, None, ignoretz, &HashMap::new())
+                .unwrap();
+            assert_eq!((naive, offset), general(&parser, timestr, ignoretz), "{}", timestr);
+            assert_eq!(tokens, None);
+        }
+    }
+}
+
+#[test]
+fn falls_back_to_general_parser() {
+    let parser = Parser::default();
+    let tzinfos = HashMap::new();
+    let parse = |timestr, dayfirst| parser.parse(timestr, dayfirst, None, false, false, None, false, &tzinfos);
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+    // The general parser clamps the day of the month
+    assert_eq!(parse("2024-02-30", None).unwrap().0, ymd(2024, 2, 29));
+    assert_eq!(parse("2024-03-05", Some(true)).unwrap().0, ymd(2024, 5, 3));
+    assert!(parse("2024-03-05T24:00:00", None).is_err());
+    assert!(parse("2024-03-05T14:30:00+01:00:00", None).is_err());
+
+    let fuzzy = parser
+        .parse("2024-03-05", None, None, true, true, None, false, &tzinfos)
+        .unwrap();
+    assert_eq!(fuzzy.2, Some(vec![]));
+
+    let strict = Parser::default().with_ambiguous_date_errors(true);
+    let parsed = strict.parse("2024-03-05", None, None, false, false, None, false, &tzinfos);
+    assert_eq!(parsed.unwrap().0, ymd(2024, 3, 5));
+}
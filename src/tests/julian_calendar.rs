@@ -0,0 +1,113 @@
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use parse;
+use parse_info;
+use CalendarSystem;
+use ParseError;
+use Parser;
+use ParserInfo;
+
+fn midnight(y: i32, m: u32, d: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+}
+
+fn parse_date(timestr: &str) -> Result<NaiveDateTime, ParseError> {
+    parse(timestr).map(|res| res.0)
+}
+
+fn british() -> Parser {
+    Parser::default().with_julian_reform(NaiveDate::from_ymd_opt(1752, 9, 14).unwrap())
+}
+
+fn parse_with(parser: &Parser, timestr: &str) -> Result<NaiveDateTime, ParseError> {
+    let default = midnight(2024, 3, 5);
+    parser
+        .parse(timestr, None, None, false, false, Some(&default), false, &HashMap::new())
+        .map(|res| res.0)
+}
+
+#[test]
+fn style_markers() {
+    assert_eq!(parse_date("5 March 1700 O.S."), Ok(midnight(1700, 3, 16)));
+    assert_eq!(parse_date("5 March 1700 Old Style"), Ok(midnight(1700, 3, 16)));
+    assert_eq!(parse_date("5 March 1700 N.S."), Ok(midnight(1700, 3, 5)));
+    assert_eq!(parse_date("O.S. 4 October 1582"), Ok(midnight(1582, 10, 14)));
+    assert_eq!(parse_date("25 October 1917 O. S."), Ok(midnight(1917, 11, 7)));
+}
+
+#[test]
+fn julian_leap_days() {
+    // 1700 was a leap year only in the Julian calendar
+    assert_eq!(parse_date("29 Feb 1700 O.S."), Ok(midnight(1700, 3, 11)));
+    assert_eq!(parse_date("29 Feb 1700"), Ok(midnight(1700, 2, 28)));
+    assert_eq!(parse_date("30 Feb 1700 O.S."), Ok(midnight(1700, 3, 11)));
+}
+
+#[test]
+fn split_years() {
+    assert_eq!(parse_date("10 February 1709/10"), Ok(midnight(1710, 2, 10)));
+    assert_eq!(parse_date("1 Jan 1700/01"), Ok(midnight(1701, 1, 1)));
+    assert_eq!(parse_date("March 24, 1699/0"), Ok(midnight(1700, 3, 24)));
+    assert_eq!(parse_date("10 February 1709/10 O.S."), Ok(midnight(1710, 2, 21)));
+    assert!(parse_date("1 Jan 1799/1800").is_err());
+
+    // Without a month name before it, the number after the slash is the month
+    let default = Parser::default();
+    assert_eq!(parse_with(&default, "2011/12"), Ok(midnight(2011, 12, 5)));
+    assert_eq!(parse_with(&default, "2011/12/10"), Ok(midnight(2011, 12, 10)));
+}
+
+#[test]
+fn reform_date() {
+    let parser = british();
+    assert_eq!(parse_with(&parser, "2 September 1752"), Ok(midnight(1752, 9, 13)));
+    assert_eq!(parse_with(&parser, "14 September 1752"), Ok(midnight(1752, 9, 14)));
+    assert_eq!(parse_with(&parser, "1 Jan 1700/01"), Ok(midnight(1701, 1, 12)));
+    assert_eq!(parse_with(&parser, "5 March 1700 N.S."), Ok(midnight(1700, 3, 5)));
+    assert_eq!(parse_with(&parser, "5 March 1800 O.S."), Ok(midnight(1800, 3, 17)));
+    assert_eq!(parse_with(&parser, "2024-03-05"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "1700-03-05"), Ok(midnight(1700, 3, 16)));
+    assert_eq!(
+        parse_with(&parser, "1752-09-02T12:00Z"),
+        Ok(midnight(1752, 9, 13) + Duration::hours(12))
+    );
+}
+
+#[test]
+fn reports_the_calendar() {
+    let calendar = |parser: &Parser, timestr: &str| {
+        parser
+            .parse_scored(timestr, None, None, None, false, &HashMap::new())
+            .unwrap()
+            .calendar
+    };
+
+    let default = Parser::default();
+    assert_eq!(calendar(&default, "5 March 1700 O.S."), CalendarSystem::Julian);
+    assert_eq!(calendar(&default, "5 March 1700"), CalendarSystem::Gregorian);
+    assert_eq!(calendar(&british(), "5 March 1700"), CalendarSystem::Julian);
+    assert_eq!(calendar(&british(), "5 March 1700 N.S."), CalendarSystem::Gregorian);
+}
+
+#[test]
+fn reports_the_calendar_strictly() {
+    let detailed = |timestr: &str| british().parse_detailed(timestr, None, None, None, false, &HashMap::new());
+    assert_eq!(detailed("5 March 1700").unwrap().calendar, CalendarSystem::Julian);
+    assert_eq!(detailed("5 March 1700 N.S.").unwrap().calendar, CalendarSystem::Gregorian);
+    assert!(detailed("Born 5 March 1700 O.S.").is_err());
+}
+
+#[test]
+fn localized_markers() {
+    let info = ParserInfo {
+        calendar_style: parse_info(vec![vec!["st. st."], vec!["n. st."]]),
+        ..ParserInfo::default()
+    };
+    let parser = Parser::new(info);
+    assert_eq!(parse_with(&parser, "25 October 1917 st. st."), Ok(midnight(1917, 11, 7)));
+    assert_eq!(parse_with(&parser, "7 November 1917 n. st."), Ok(midnight(1917, 11, 7)));
+    assert!(parse_with(&parser, "25 October 1917 O.S.").is_err());
+}
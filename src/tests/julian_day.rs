@@ -0,0 +1,53 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+
+use parse;
+use ParseError;
+
+fn parse_naive(s: &str) -> Result<NaiveDateTime, ParseError> {
+    parse(s).map(|res| res.0)
+}
+
+#[test]
+fn julian_day_prefixes() {
+    let expected = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+    assert_eq!(parse_naive("JD 2460374.5"), Ok(expected));
+    assert_eq!(parse_naive("MJD 60374.0"), Ok(expected));
+    assert_eq!(parse_naive("mjd60374"), Ok(expected));
+    assert_eq!(parse_naive("RJD 60374.5"), Ok(expected));
+}
+
+#[test]
+fn julian_day_fractions() {
+    assert_eq!(
+        parse_naive("JD 2451545.25"),
+        Ok(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(18, 0, 0).unwrap())
+    );
+    assert_eq!(
+        parse_naive("MJD 51544.0001"),
+        Ok(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_milli_opt(0, 0, 8, 640).unwrap())
+    );
+}
+
+#[test]
+fn julian_day_before_gregorian_reform() {
+    // Julian Day zero, 4713 BC in the Julian calendar
+    assert_eq!(
+        parse_naive("JD 0"),
+        Ok(NaiveDate::from_ymd_opt(-4713, 11, 24).unwrap().and_hms_opt(12, 0, 0).unwrap())
+    );
+    assert_eq!(
+        parse_naive("JD 1721425.5"),
+        Ok(NaiveDate::from_ymd_opt(1, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    );
+    assert_eq!(
+        parse_naive("MJD -100840"),
+        Ok(NaiveDate::from_ymd_opt(1582, 10, 15).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    );
+}
+
+#[test]
+fn julian_day_without_number() {
+    assert_eq!(parse_naive("JD"), Err(ParseError::UnrecognizedToken("JD".to_owned())));
+}
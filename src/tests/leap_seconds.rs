@@ -0,0 +1,68 @@
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use parse;
+use ParseError;
+use ParseResult;
+use Parser;
+
+fn leap(y: i32, m: u32, d: u32, h: u32, min: u32, nano: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_nano_opt(h, min, 59, 1_000_000_000 + nano)
+        .unwrap()
+}
+
+fn parse_validated(timestr: &str) -> ParseResult<(NaiveDateTime, Option<FixedOffset>)> {
+    let mut tzinfos = HashMap::new();
+    tzinfos.insert("BRST".to_owned(), -10_800);
+    let parser = Parser::default().with_leap_second_validation(true);
+    let res = parser.parse(timestr, None, None, false, false, None, false, &tzinfos)?;
+    Ok((res.0, res.1))
+}
+
+#[test]
+fn second_sixty() {
+    assert_eq!(
+        parse("2016-12-31T23:59:60Z"),
+        Ok((leap(2016, 12, 31, 23, 59, 0), FixedOffset::east_opt(0)))
+    );
+    assert_eq!(
+        parse("2016-12-31 23:59:60.25"),
+        Ok((leap(2016, 12, 31, 23, 59, 250_000_000), None))
+    );
+    assert_eq!(
+        parse("Dec 31 2016 11:59:60 PM"),
+        Ok((leap(2016, 12, 31, 23, 59, 0), None))
+    );
+    assert_eq!(parse("2024-03-05 14:30:60"), Ok((leap(2024, 3, 5, 14, 30, 0), None)));
+    assert_eq!(
+        parse("2016-12-31 23:59:61"),
+        Err(ParseError::ImpossibleTimestamp("Invalid second"))
+    );
+}
+
+#[test]
+fn validated_leap_seconds() {
+    assert!(parse_validated("2016-12-31T23:59:60Z").is_ok());
+    assert!(parse_validated("1972-06-30 23:59:60").is_ok());
+    assert!(parse_validated("2017-01-01T00:59:60+01:00").is_ok());
+    assert!(parse_validated("2015-06-30 20:59:60.5 BRST").is_ok());
+    assert!(parse_validated("2024-03-05 14:30:00").is_ok());
+
+    for timestr in &[
+        "2024-03-05 14:30:60",
+        "2017-12-31T23:59:60Z",
+        "2016-12-31T23:59:60+01:00",
+        "1971-12-31 23:59:60",
+    ] {
+        assert_eq!(
+            parse_validated(timestr),
+            Err(ParseError::ImpossibleTimestamp("Invalid leap second")),
+            "Accepted '{}'",
+            timestr
+        );
+    }
+}
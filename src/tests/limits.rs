@@ -0,0 +1,113 @@
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use column::ColumnParser;
+use Limits;
+use ParseError;
+use ParseResult;
+use Parser;
+use TimestampMode;
+
+fn parse_limited(limits: Limits, timestr: &str, fuzzy: bool) -> ParseResult<chrono::NaiveDateTime> {
+    let parser = Parser::default().with_limits(limits);
+    let res = parser.parse(timestr, None, None, fuzzy, false, None, false, &HashMap::new())?;
+    Ok(res.0)
+}
+
+#[test]
+fn max_bytes() {
+    let limits = Limits {
+        max_bytes: Some(16),
+        ..Limits::default()
+    };
+    assert!(parse_limited(limits, "Sep 25 2003", false).is_ok());
+    assert!(parse_limited(limits, "2024-03-05T14:30", false).is_ok());
+    assert_eq!(
+        parse_limited(limits, "2024-03-05T14:30:00", false),
+        Err(ParseError::LimitExceeded("Input too long"))
+    );
+
+    let parser = Parser::default().with_limits(limits);
+    let long = "September 25 2003";
+    let tzinfos = HashMap::new();
+    assert!(parser.parse_scored(long, None, None, None, false, &tzinfos).is_err());
+    assert!(parser.parse_candidates(long, false, None, false, &tzinfos).is_err());
+    assert!(parser.infer_format(long).is_err());
+}
+
+#[test]
+fn max_tokens() {
+    let limits = Limits {
+        max_tokens: Some(5),
+        ..Limits::default()
+    };
+    assert!(parse_limited(limits, "10:49 AM", false).is_ok());
+    assert_eq!(
+        parse_limited(limits, "10:49:41 AM", false),
+        Err(ParseError::LimitExceeded("Too many tokens"))
+    );
+    // Also applies to strings the ISO 8601 fast path would take
+    assert_eq!(
+        parse_limited(limits, "2024-03-05T14:30", false),
+        Err(ParseError::LimitExceeded("Too many tokens"))
+    );
+}
+
+#[test]
+fn max_digits() {
+    let limits = Limits {
+        max_digits: Some(6),
+        ..Limits::default()
+    };
+    assert!(parse_limited(limits, "10:49:41.502", false).is_ok());
+    assert_eq!(
+        parse_limited(limits, "10:49:41.50231", false),
+        Err(ParseError::LimitExceeded("Too many digits in a number"))
+    );
+    assert_eq!(
+        parse_limited(limits, &format!("Sep 25 2003 {}", "9".repeat(1000)), true),
+        Err(ParseError::LimitExceeded("Too many digits in a number"))
+    );
+
+    let parser = Parser::default()
+        .with_limits(limits)
+        .with_timestamps(TimestampMode::default());
+    let res = parser.parse("1412409095", None, None, false, false, None, false, &HashMap::new());
+    assert_eq!(res, Err(ParseError::LimitExceeded("Too many digits in a number")));
+}
+
+#[test]
+fn max_skipped_tokens() {
+    let limits = Limits {
+        max_skipped_tokens: Some(3),
+        ..Limits::default()
+    };
+    let ymd = NaiveDate::from_ymd_opt(2018, 6, 17).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+    // Spaces, punctuation and jump words like "on" and "of" aren't counted
+    assert_eq!(parse_limited(limits, "released on the 17th of June, 2018", true), Ok(ymd));
+    assert_eq!(
+        parse_limited(limits, "I first released it on the 17th of June, 2018", true),
+        Err(ParseError::LimitExceeded("Too many skipped tokens"))
+    );
+
+    let parser = Parser::default().with_limits(limits);
+    let scored = parser.parse_scored("a b c d 2018-06-17", None, None, None, false, &HashMap::new());
+    assert_eq!(scored, Err(ParseError::LimitExceeded("Too many skipped tokens")));
+}
+
+#[test]
+fn column_respects_limits() {
+    let limits = Limits {
+        max_bytes: Some(10),
+        ..Limits::default()
+    };
+    let mut column = ColumnParser::new(Parser::default().with_limits(limits), 2);
+    assert!(column.parse("2024-03-05").is_ok());
+    assert!(column.parse("2024-03-06").is_ok());
+    assert_eq!(column.layout(), Some("%Y-%m-%d"));
+
+    assert!(column.parse("2024-03-07").is_ok());
+    assert_eq!(column.parse(" 2024-03-07"), Err(ParseError::LimitExceeded("Input too long")));
+    assert_eq!(column.fast_parsed(), 1);
+}
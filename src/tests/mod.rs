@@ -1,7 +1,26 @@
+mod arithmetic_calendars;
+mod calendar;
+mod candidates;
+mod column;
+mod confidence;
+mod eras;
+mod expanded_years;
+mod fraction_rounding;
+mod fractional_units;
 mod fuzzing;
+mod infer_format;
+mod iso;
+mod julian_calendar;
+mod julian_day;
+mod leap_seconds;
+mod limits;
+mod offset_years;
 mod pycompat_parser;
 mod pycompat_tokenizer;
+mod regnal_eras;
+mod timestamps;
 
+use chrono::FixedOffset;
 use chrono::NaiveDate;
 use crate::parse;
 
@@ -11,4 +30,15 @@ fn nanosecond_precision() {
         parse("2008.12.29T08:09:10.123456789").unwrap(),
         (NaiveDate::from_ymd_opt(2008, 12, 29).unwrap().and_hms_nano_opt(8, 9, 10, 123_456_789).unwrap(), None)
     )
-}
\ No newline at end of file
+}
+
+#[test]
+fn offset_minutes() {
+    assert_eq!(
+        parse("2024-03-05 14:30 +0530").unwrap(),
+        (
+            NaiveDate::from_ymd_opt(2024, 3, 5).unwrap().and_hms_opt(14, 30, 0).unwrap(),
+            FixedOffset::east_opt(5 * 3600 + 30 * 60)
+        )
+    )
+}
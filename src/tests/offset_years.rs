@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use parse_info;
+use OffsetCalendar;
+use ParseError;
+use Parser;
+use ParserInfo;
+
+fn midnight(y: i32, m: u32, d: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+}
+
+fn parse_with(parser: &Parser, timestr: &str) -> Result<NaiveDateTime, ParseError> {
+    let default = midnight(2024, 1, 1);
+    parser
+        .parse(timestr, None, None, false, false, Some(&default), false, &HashMap::new())
+        .map(|res| res.0)
+}
+
+fn thai() -> Parser {
+    let mut info = ParserInfo::default().with_thai_buddhist_years();
+    info.months.insert("มีนาคม".to_owned(), 2);
+    Parser::new(info)
+}
+
+#[test]
+fn thai_buddhist_years() {
+    let parser = thai();
+    assert_eq!(parse_with(&parser, "5 มีนาคม 2567"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "5 มีนาคม พ.ศ. 2567"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "BE 2567"), Ok(midnight(2024, 1, 1)));
+    assert_eq!(parse_with(&parser, "March 5, 2567 B.E."), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "2567-03-05"), Ok(midnight(2024, 3, 5)));
+    // Two-digit years are completed from the current Gregorian year
+    assert_eq!(parse_with(&parser, "5/3/24"), Ok(midnight(2024, 5, 3)));
+}
+
+#[test]
+fn minguo_years() {
+    let parser = Parser::new(ParserInfo::default().with_minguo_years());
+    assert_eq!(parse_with(&parser, "民國113年3月5日"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "中華民國113年3月5日"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "113/03/05"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "民國38年"), Ok(midnight(1949, 1, 1)));
+    assert_eq!(parse_with(&parser, "ROC 1.1.1"), Ok(midnight(1912, 1, 1)));
+}
+
+#[test]
+fn marked_years_only() {
+    let info = ParserInfo {
+        offset_calendar: Some(OffsetCalendar {
+            markers: parse_info(vec![vec!["BE"]]),
+            offset: -543,
+            unmarked: false,
+        }),
+        ..ParserInfo::default()
+    };
+    let parser = Parser::new(info);
+    assert_eq!(parse_with(&parser, "5 March 2567 BE"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "5 March 2024"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "2024-03-05"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(
+        parse_with(&parser, "March BE"),
+        Err(ParseError::ImpossibleTimestamp("Era without a year"))
+    );
+}
+
+#[test]
+fn default_years_are_gregorian() {
+    let parser = Parser::default();
+    assert_eq!(parse_with(&parser, "5 March 2567"), Ok(midnight(2567, 3, 5)));
+    assert!(parse_with(&parser, "BE 2567").is_err());
+}
@@ -26,6 +26,7 @@ struct PyDateTime {
     tzo: Option<i32>
 }
 
+#[allow(clippy::too_many_arguments)]
 fn parse_and_assert(
     pdt: PyDateTime,
     info: ParserInfo,
@@ -48,7 +49,7 @@ fn parse_and_assert(
         fuzzy_with_tokens,
         default,
         ignoretz,
-        tzinfos).expect(&format!("Unable to parse date in Rust '{}'", s));
+        tzinfos).unwrap_or_else(|_| panic!("Unable to parse date in Rust '{}'", s));
 
     assert_eq!(pdt.year, rs_parsed.0.year(), "Year mismatch for '{}'", s);
     assert_eq!(pdt.month, rs_parsed.0.month(), "Month mismatch for '{}'", s);
@@ -56,7 +57,7 @@ fn parse_and_assert(
     assert_eq!(pdt.hour, rs_parsed.0.hour(), "Hour mismatch for '{}'", s);
     assert_eq!(pdt.minute, rs_parsed.0.minute(), "Minute mismatch f'or' {}", s);
     assert_eq!(pdt.second, rs_parsed.0.second(), "Second mismatch for '{}'", s);
-    assert_eq!(pdt.micros, rs_parsed.0.timestamp_subsec_micros(), "Microsecond mismatch for '{}'", s);
+    assert_eq!(pdt.micros, rs_parsed.0.nanosecond() / 1000, "Microsecond mismatch for '{}'", s);
     assert_eq!(pdt.tzo, rs_parsed.1.map(|u| u.local_minus_utc()), "Timezone Offset mismatch for '{}'", s);
 }
 
@@ -64,17 +65,18 @@ fn parse_and_assert_simple(
     pdt: PyDateTime,
     s: &str,
 ) {
-    let rs_parsed = parse(s).expect(&format!("Unable to parse date in Rust '{}'", s));
+    let rs_parsed = parse(s).unwrap_or_else(|_| panic!("Unable to parse date in Rust '{}'", s));
     assert_eq!(pdt.year, rs_parsed.0.year(), "Year mismatch for '{}'", s);
     assert_eq!(pdt.month, rs_parsed.0.month(), "Month mismatch for '{}'", s);
     assert_eq!(pdt.day, rs_parsed.0.day(), "Day mismatch for '{}'", s);
     assert_eq!(pdt.hour, rs_parsed.0.hour(), "Hour mismatch for '{}'", s);
     assert_eq!(pdt.minute, rs_parsed.0.minute(), "Minute mismatch for '{}'", s);
     assert_eq!(pdt.second, rs_parsed.0.second(), "Second mismatch for '{}'", s);
-    assert_eq!(pdt.micros, rs_parsed.0.timestamp_subsec_micros(), "Microsecond mismatch for '{}'", s);
+    assert_eq!(pdt.micros, rs_parsed.0.nanosecond() / 1000, "Microsecond mismatch for '{}'", s);
     assert_eq!(pdt.tzo, rs_parsed.1.map(|u| u.local_minus_utc()), "Timezone Offset mismatch for '{}'", s);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn parse_fuzzy_and_assert(
     pdt: PyDateTime,
     ptokens: Option<Vec<String>>,
@@ -98,7 +100,7 @@ fn parse_fuzzy_and_assert(
         fuzzy_with_tokens,
         default,
         ignoretz,
-        tzinfos).expect(&format!("Unable to parse date in Rust '{}'", s));
+        tzinfos).unwrap_or_else(|_| panic!("Unable to parse date in Rust '{}'", s));
 
     assert_eq!(pdt.year, rs_parsed.0.year(), "Year mismatch for '{}'", s);
     assert_eq!(pdt.month, rs_parsed.0.month(), "Month mismatch for '{}'", s);
@@ -106,7 +108,7 @@ fn parse_fuzzy_and_assert(
     assert_eq!(pdt.hour, rs_parsed.0.hour(), "Hour mismatch for '{}'", s);
     assert_eq!(pdt.minute, rs_parsed.0.minute(), "Minute mismatch f'or' {}", s);
     assert_eq!(pdt.second, rs_parsed.0.second(), "Second mismatch for '{}'", s);
-    assert_eq!(pdt.micros, rs_parsed.0.timestamp_subsec_micros(), "Microsecond mismatch for '{}'", s);
+    assert_eq!(pdt.micros, rs_parsed.0.nanosecond() / 1000, "Microsecond mismatch for '{}'", s);
     assert_eq!(pdt.tzo, rs_parsed.1.map(|u| u.local_minus_utc()), "Timezone Offset mismatch for '{}'", s);
     assert_eq!(ptokens, rs_parsed.2, "Tokens mismatch for '{}'", s);
 }
@@ -0,0 +1,102 @@
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+
+use ParseError;
+use Parser;
+use ParserInfo;
+use RegnalEra;
+
+fn midnight(y: i32, m: u32, d: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap()
+}
+
+fn japanese() -> Parser {
+    Parser::new(ParserInfo::default().with_japanese_eras())
+}
+
+fn parse_with(parser: &Parser, timestr: &str) -> Result<NaiveDateTime, ParseError> {
+    let default = midnight(2024, 1, 1);
+    parser
+        .parse(timestr, None, None, false, false, Some(&default), false, &HashMap::new())
+        .map(|res| res.0)
+}
+
+#[test]
+fn kanji_eras() {
+    let parser = japanese();
+    assert_eq!(parse_with(&parser, "令和6年3月5日"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "平成31年4月30日"), Ok(midnight(2019, 4, 30)));
+    assert_eq!(parse_with(&parser, "昭和64年1月7日"), Ok(midnight(1989, 1, 7)));
+    assert_eq!(parse_with(&parser, "大正15年12月24日"), Ok(midnight(1926, 12, 24)));
+    assert_eq!(parse_with(&parser, "明治45年7月29日"), Ok(midnight(1912, 7, 29)));
+}
+
+#[test]
+fn first_year() {
+    let parser = japanese();
+    assert_eq!(parse_with(&parser, "令和元年5月1日"), Ok(midnight(2019, 5, 1)));
+    assert_eq!(parse_with(&parser, "平成元年1月8日"), Ok(midnight(1989, 1, 8)));
+    assert_eq!(parse_with(&parser, "令和1年5月1日"), Ok(midnight(2019, 5, 1)));
+}
+
+#[test]
+fn initials_and_romaji() {
+    let parser = japanese();
+    assert_eq!(parse_with(&parser, "R6.3.5"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "H31.4.30"), Ok(midnight(2019, 4, 30)));
+    assert_eq!(parse_with(&parser, "H31/04/30"), Ok(midnight(2019, 4, 30)));
+    assert_eq!(parse_with(&parser, "S64.1.7"), Ok(midnight(1989, 1, 7)));
+    assert_eq!(parse_with(&parser, "Reiwa 6 March 5"), Ok(midnight(2024, 3, 5)));
+    assert_eq!(parse_with(&parser, "Showa 50.1.1"), Ok(midnight(1975, 1, 1)));
+}
+
+#[test]
+fn dates_outside_the_era() {
+    let parser = japanese();
+    let outside = Err(ParseError::ImpossibleTimestamp("Date outside era"));
+    assert_eq!(parse_with(&parser, "平成31年5月1日"), outside);
+    assert_eq!(parse_with(&parser, "令和元年4月30日"), outside);
+    assert_eq!(parse_with(&parser, "H32.1.1"), outside);
+    assert_eq!(parse_with(&parser, "T1.7.29"), outside);
+}
+
+#[test]
+fn initials_only_start_dates() {
+    let parser = japanese();
+    assert_eq!(
+        parse_with(&parser, "2024-03-05T10:30"),
+        Ok(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap().and_hms_opt(10, 30, 0).unwrap())
+    );
+    assert_eq!(
+        parse_with(&parser, "T10:30"),
+        Ok(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(10, 30, 0).unwrap())
+    );
+    assert_eq!(
+        parse_with(&parser, "10 h 30 m"),
+        Ok(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(10, 30, 0).unwrap())
+    );
+    // Without the eras, "R6.3.5" is just unrecognized
+    assert!(parse_with(&Parser::default(), "R6.3.5").is_err());
+}
+
+#[test]
+fn custom_eras() {
+    let info = ParserInfo {
+        regnal_eras: vec![
+            RegnalEra {
+                names: vec!["Elizabeth".to_owned()],
+                start: NaiveDate::from_ymd_opt(1952, 2, 6).unwrap(),
+            },
+            RegnalEra {
+                names: vec!["Charles".to_owned()],
+                start: NaiveDate::from_ymd_opt(2022, 9, 8).unwrap(),
+            },
+        ],
+        ..ParserInfo::default()
+    };
+    let parser = Parser::new(info);
+    assert_eq!(parse_with(&parser, "Elizabeth 70 June 1"), Ok(midnight(2021, 6, 1)));
+    assert_eq!(parse_with(&parser, "Charles 2 May 6"), Ok(midnight(2023, 5, 6)));
+    assert!(parse_with(&parser, "Elizabeth 72 December 1").is_err());
+}
@@ -0,0 +1,110 @@
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use epoch::Epoch;
+use ParseError;
+use Parser;
+use TimestampMode;
+use TimestampUnit;
+
+fn parse_ts(mode: TimestampMode, s: &str) -> Result<chrono::NaiveDateTime, ParseError> {
+    let p = Parser::default().with_timestamps(mode);
+    let res = p.parse(s, None, None, false, false, None, false, &HashMap::new())?;
+    assert_eq!(res.1, FixedOffset::east_opt(0));
+    Ok(res.0)
+}
+
+#[test]
+fn detect_units() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let mode = TimestampMode::default();
+
+    assert_eq!(
+        parse_ts(mode, "1412409095"),
+        Ok(ymd(2014, 10, 4).and_hms_opt(7, 51, 35).unwrap())
+    );
+    assert_eq!(
+        parse_ts(mode, "1412409095009"),
+        Ok(ymd(2014, 10, 4).and_hms_milli_opt(7, 51, 35, 9).unwrap())
+    );
+    assert_eq!(
+        parse_ts(mode, "1566997680962280"),
+        Ok(ymd(2019, 8, 28).and_hms_micro_opt(13, 8, 0, 962_280).unwrap())
+    );
+    assert_eq!(
+        parse_ts(mode, "1566997680962280123"),
+        Ok(ymd(2019, 8, 28).and_hms_nano_opt(13, 8, 0, 962_280_123).unwrap())
+    );
+    assert_eq!(
+        parse_ts(mode, "@1412409095.25"),
+        Ok(ymd(2014, 10, 4).and_hms_milli_opt(7, 51, 35, 250).unwrap())
+    );
+}
+
+#[test]
+fn negative_timestamps() {
+    let mode = TimestampMode::Unit(TimestampUnit::Seconds);
+    assert_eq!(
+        parse_ts(mode, "-86400.5"),
+        Ok(NaiveDate::from_ymd_opt(1969, 12, 30).unwrap().and_hms_milli_opt(23, 59, 59, 500).unwrap())
+    );
+    assert_eq!(
+        parse_ts(TimestampMode::default(), "@-1000000000000"),
+        Ok(NaiveDate::from_ymd_opt(1938, 4, 24).unwrap().and_hms_opt(22, 13, 20).unwrap())
+    );
+}
+
+#[test]
+fn explicit_unit() {
+    let mode = TimestampMode::Unit(TimestampUnit::Milliseconds);
+    assert_eq!(
+        parse_ts(mode, "1500"),
+        Ok(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_milli_opt(0, 0, 1, 500).unwrap())
+    );
+}
+
+#[test]
+fn out_of_range() {
+    assert_eq!(
+        parse_ts(TimestampMode::default(), "99999999999999999999999999999"),
+        Err(ParseError::ImpossibleTimestamp("Timestamp out of range"))
+    );
+    assert_eq!(
+        parse_ts(TimestampMode::Unit(TimestampUnit::Seconds), "99999999999999999"),
+        Err(ParseError::ImpossibleTimestamp("Timestamp out of range"))
+    );
+}
+
+#[test]
+fn non_numeric_falls_through() {
+    assert_eq!(
+        parse_ts(TimestampMode::default(), "2014-10-04 07:51:35Z"),
+        Ok(NaiveDate::from_ymd_opt(2014, 10, 4).unwrap().and_hms_opt(7, 51, 35).unwrap())
+    );
+}
+
+#[test]
+fn alternate_epochs() {
+    let expected = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_milli_opt(0, 0, 0, 500).unwrap();
+
+    assert_eq!(parse_ts(TimestampMode::Epoch(Epoch::FileTime), "132223104005000000"), Ok(expected));
+    assert_eq!(parse_ts(TimestampMode::Epoch(Epoch::Cocoa), "599529600.5"), Ok(expected));
+    assert_eq!(parse_ts(TimestampMode::Epoch(Epoch::WebKit), "13222310400500000"), Ok(expected));
+    assert_eq!(
+        parse_ts(TimestampMode::Epoch(Epoch::Ntp), "16264292109803061248"),
+        Ok(expected)
+    );
+}
+
+#[test]
+fn spreadsheet_serials() {
+    assert_eq!(
+        parse_ts(TimestampMode::Epoch(Epoch::Excel1900), "45356.604166"),
+        Ok(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap().and_hms_milli_opt(14, 29, 59, 942).unwrap())
+    );
+    assert_eq!(
+        parse_ts(TimestampMode::Epoch(Epoch::Excel1900), "60"),
+        Err(ParseError::ImpossibleTimestamp("Timestamp out of range"))
+    );
+}
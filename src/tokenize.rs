@@ -1,10 +1,16 @@
-pub(crate) struct Tokenizer {
-    token_stack: Vec<String>,
-    // TODO: Should this be more generic? io::Read for example?
-    parse_string: String,
+use std::borrow::Cow;
+
+/// Splits a string into the same tokens as dateutil's `_timelex`, without
+/// copying it. Tokens borrow from the input and record where they were found.
+pub(crate) struct Tokenizer<'a> {
+    parse_string: &'a str,
+    /// Byte offset of the next character to read
+    pos: usize,
+    /// Remainder of a token that dateutil splits apart at every '.' and ','
+    split: Option<(usize, usize)>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum ParseState {
     Empty,
     Alpha,
@@ -13,11 +19,44 @@ pub(crate) enum ParseState {
     NumericDecimal,
 }
 
-impl Tokenizer {
-    pub(crate) fn new(parse_string: &str) -> Self {
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TokenKind {
+    Plain,
+    /// A single whitespace character, which dateutil turns into " "
+    Space,
+    /// A number like "41,502" using a comma as its decimal separator, which
+    /// dateutil turns into "41.502"
+    DecimalComma,
+}
+
+/// A token found by the `Tokenizer`: bytes `start..end` of the input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Token<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    kind: TokenKind,
+}
+
+impl<'a> Token<'a> {
+    /// The token as dateutil produces it. This is `text` except for
+    /// whitespace, which becomes " ", and numbers with a decimal comma, which
+    /// get a dot instead; only the latter allocates.
+    pub(crate) fn value(&self) -> Cow<'a, str> {
+        match self.kind {
+            TokenKind::Plain => Cow::Borrowed(self.text),
+            TokenKind::Space => Cow::Borrowed(" "),
+            TokenKind::DecimalComma => Cow::Owned(self.text.replace(',', ".")),
+        }
+    }
+}
+
+impl<'a> Tokenizer<'a> {
+    pub(crate) fn new(parse_string: &'a str) -> Self {
         Tokenizer {
-            token_stack: vec![],
-            parse_string: parse_string.chars().rev().collect(),
+            parse_string,
+            pos: 0,
+            split: None,
         }
     }
 
@@ -33,149 +72,130 @@ impl Tokenizer {
         c.is_whitespace()
     }
 
-    fn decimal_split(&self, s: &str) -> Vec<String> {
-        // Handles the same thing as Python's re.split()
-        let mut tokens: Vec<String> = vec!["".to_owned()];
-
-        for c in s.chars() {
-            if c == '.' || c == ',' {
-                tokens.push(c.to_string());
-                tokens.push("".to_owned());
-            } else {
-                // UNWRAP: Initial setup guarantees we always have an item
-                let mut t = tokens.pop().unwrap();
-                t.push(c);
-                tokens.push(t);
-            }
-        }
-
-        // TODO: Do I really have to use &String instead of &str?
-        if tokens.last() == Some(&"".to_owned()) {
-            tokens.pop();
+    fn token(&self, start: usize, end: usize, kind: TokenKind) -> Token<'a> {
+        Token {
+            text: &self.parse_string[start..end],
+            start,
+            end,
+            kind,
         }
+    }
 
-        tokens
+    /// Handles the same thing as Python's re.split() on `([.,])`, one piece at
+    /// a time: each '.' and ',' is a token, as is the text between them
+    fn next_split(&mut self, start: usize, end: usize) -> Token<'a> {
+        let rest = &self.parse_string[start..end];
+        let len = match rest.find(['.', ',']) {
+            Some(0) => 1,
+            Some(idx) => idx,
+            None => rest.len(),
+        };
+
+        self.split = if start + len < end {
+            Some((start + len, end))
+        } else {
+            None
+        };
+        self.token(start, start + len, TokenKind::Plain)
     }
 }
 
-impl Iterator for Tokenizer {
-    type Item = String;
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if !self.token_stack.is_empty() {
-            return Some(self.token_stack.remove(0));
+        if let Some((start, end)) = self.split {
+            return Some(self.next_split(start, end));
         }
 
+        let start = self.pos;
         let mut seenletters = false;
-        let mut token: Option<String> = None;
         let mut state = ParseState::Empty;
 
-        while !self.parse_string.is_empty() {
-            // Dateutil uses a separate `charstack` to manage the incoming stream.
-            // Because parse_string can have things pushed back onto it, we skip
-            // a couple of steps related to the `charstack`.
-
-            // UNWRAP: Just checked that parse_string isn't empty
-            let nextchar = self.parse_string.pop().unwrap();
-
-            match state {
+        while let Some(nextchar) = self.parse_string[self.pos..].chars().next() {
+            let (accept, next_state) = match state {
                 ParseState::Empty => {
-                    token = Some(nextchar.to_string());
                     if self.isword(nextchar) {
-                        state = ParseState::Alpha;
+                        (true, ParseState::Alpha)
                     } else if self.isnum(nextchar) {
-                        state = ParseState::Numeric;
+                        (true, ParseState::Numeric)
                     } else if self.isspace(nextchar) {
-                        token = Some(" ".to_owned());
-                        break;
+                        self.pos += nextchar.len_utf8();
+                        return Some(self.token(start, self.pos, TokenKind::Space));
                     } else {
+                        self.pos += nextchar.len_utf8();
                         break;
                     }
                 }
                 ParseState::Alpha => {
                     seenletters = true;
                     if self.isword(nextchar) {
-                        // UNWRAP: Because we're in non-empty parse state, we're guaranteed to have a token
-                        token.as_mut().unwrap().push(nextchar);
+                        (true, ParseState::Alpha)
                     } else if nextchar == '.' {
-                        token.as_mut().unwrap().push(nextchar);
-                        state = ParseState::AlphaDecimal;
+                        (true, ParseState::AlphaDecimal)
                     } else {
-                        self.parse_string.push(nextchar);
-                        break;
+                        (false, state)
                     }
                 }
                 ParseState::Numeric => {
                     if self.isnum(nextchar) {
-                        // UNWRAP: Because we're in non-empty parse state, we're guaranteed to have a token
-                        token.as_mut().unwrap().push(nextchar);
-                    } else if nextchar == '.'
-                        || (nextchar == ',' && token.as_ref().unwrap().len() >= 2)
-                    {
-                        token.as_mut().unwrap().push(nextchar);
-                        state = ParseState::NumericDecimal;
+                        (true, ParseState::Numeric)
+                    } else if nextchar == '.' || (nextchar == ',' && self.pos - start >= 2) {
+                        (true, ParseState::NumericDecimal)
                     } else {
-                        self.parse_string.push(nextchar);
-                        break;
+                        (false, state)
                     }
                 }
                 ParseState::AlphaDecimal => {
                     seenletters = true;
                     if nextchar == '.' || self.isword(nextchar) {
-                        // UNWRAP: Because we're in non-empty parse state, we're guaranteed to have a token
-                        token.as_mut().unwrap().push(nextchar);
-                    } else if self.isnum(nextchar) && token.as_ref().unwrap().ends_with('.') {
-                        token.as_mut().unwrap().push(nextchar);
-                        state = ParseState::NumericDecimal;
+                        (true, ParseState::AlphaDecimal)
+                    } else if self.isnum(nextchar) && self.parse_string[..self.pos].ends_with('.') {
+                        (true, ParseState::NumericDecimal)
                     } else {
-                        self.parse_string.push(nextchar);
-                        break;
+                        (false, state)
                     }
                 }
                 ParseState::NumericDecimal => {
                     if nextchar == '.' || self.isnum(nextchar) {
-                        // UNWRAP: Because we're in non-empty parse state, we're guaranteed to have a token
-                        token.as_mut().unwrap().push(nextchar);
-                    } else if self.isword(nextchar) && token.as_ref().unwrap().ends_with('.') {
-                        token.as_mut().unwrap().push(nextchar);
-                        state = ParseState::AlphaDecimal;
+                        (true, ParseState::NumericDecimal)
+                    } else if self.isword(nextchar) && self.parse_string[..self.pos].ends_with('.') {
+                        (true, ParseState::AlphaDecimal)
                     } else {
-                        self.parse_string.push(nextchar);
-                        break;
+                        (false, state)
                     }
                 }
+            };
+
+            if !accept {
+                break;
             }
+            self.pos += nextchar.len_utf8();
+            state = next_state;
+        }
+
+        if self.pos == start {
+            return None;
         }
 
         // Python uses the state to short-circuit and make sure it doesn't run into issues with None
         // We do something slightly different to express the same logic
+        let mut kind = TokenKind::Plain;
         if state == ParseState::AlphaDecimal || state == ParseState::NumericDecimal {
-            // UNWRAP: The state check guarantees that we have a value
-            let dot_count = token
-                .as_ref()
-                .unwrap()
-                .chars()
-                .filter(|c| *c == '.')
-                .count();
-            let last_char = token.as_ref().unwrap().chars().last();
-            let last_splittable = last_char == Some('.') || last_char == Some(',');
+            let text = &self.parse_string[start..self.pos];
+            let dot_count = text.matches('.').count();
+            let last_splittable = text.ends_with('.') || text.ends_with(',');
 
             if seenletters || dot_count > 1 || last_splittable {
-                let mut l = self.decimal_split(token.as_ref().unwrap());
-                let remaining = l.split_off(1);
-
-                token = Some(l[0].clone());
-                for t in remaining {
-                    self.token_stack.push(t);
-                }
+                return Some(self.next_split(start, self.pos));
             }
 
             if state == ParseState::NumericDecimal && dot_count == 0 {
-                token = Some(token.unwrap().replace(',', "."));
+                kind = TokenKind::DecimalComma;
             }
         }
 
-        token
+        Some(self.token(start, self.pos, kind))
     }
 }
 
@@ -186,7 +206,33 @@ mod tests {
 
     #[test]
     fn test_basic() {
-        let tokens: Vec<String> = Tokenizer::new("September of 2003,").collect();
+        let tokens: Vec<String> = Tokenizer::new("September of 2003,")
+            .map(|t| t.value().into_owned())
+            .collect();
         assert_eq!(tokens, vec!["September", " ", "of", " ", "2003", ","]);
     }
+
+    #[test]
+    fn test_offsets() {
+        let input = "10:49:41,502\tZ a.m.";
+        let tokens: Vec<_> = Tokenizer::new(input).collect();
+        for token in &tokens {
+            assert_eq!(token.text, &input[token.start..token.end]);
+        }
+
+        let values: Vec<_> = tokens.iter().map(|t| t.value()).collect();
+        assert_eq!(values, vec!["10", ":", "49", ":", "41.502", " ", "Z", " ", "a", ".", "m", "."]);
+        assert_eq!(tokens[4].text, "41,502");
+        assert_eq!(tokens[5].text, "\t");
+        assert_eq!((tokens[10].start, tokens[10].end), (17, 18));
+    }
+
+    #[test]
+    fn test_repeated_separators() {
+        // dateutil drops the empty pieces between consecutive separators
+        let values: Vec<String> = Tokenizer::new("a..m")
+            .map(|t| t.value().into_owned())
+            .collect();
+        assert_eq!(values, vec!["a", ".", ".", "m"]);
+    }
 }
@@ -0,0 +1,458 @@
+//! The word lists of a `ParserInfo`, compiled into a single sorted table so a
+//! token is looked up once, without allocating, to find every role it plays.
+
+use std::borrow::Cow;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use calendar::JulianDayKind;
+use tokenize::Tokenizer;
+use ParserInfo;
+
+/// Every role a word has in a `ParserInfo`; "m" is both a `jump` word and
+/// the `hms` word for minutes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Categories {
+    pub(crate) jump: bool,
+    pub(crate) weekday: Option<usize>,
+    /// Month number, starting from 1 for January
+    pub(crate) month: Option<usize>,
+    pub(crate) hms: Option<usize>,
+    /// `true` for PM
+    pub(crate) ampm: Option<bool>,
+    pub(crate) utczone: bool,
+    pub(crate) pertain: bool,
+    /// Offset in seconds, which is 0 for every `utczone` word
+    pub(crate) tzoffset: Option<usize>,
+    pub(crate) julian_day: Option<JulianDayKind>,
+    /// First word of a marker, like "B" of "B.C."
+    pub(crate) marker: bool,
+    /// Index of a regnal era, and whether the word includes its first year,
+    /// like "令和元年"
+    pub(crate) regnal_era: Option<(usize, bool)>,
+}
+
+const NONE: Categories = Categories {
+    jump: false,
+    weekday: None,
+    month: None,
+    hms: None,
+    ampm: None,
+    utczone: false,
+    pertain: false,
+    tzoffset: None,
+    julian_day: None,
+    marker: false,
+    regnal_era: None,
+};
+
+type Entry = (Cow<'static, str>, Categories);
+
+/// What a phrase of possibly several words, like "B.C." or "Old Style", marks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Marker {
+    /// An era, and whether it counts years before 1 AD
+    Era(bool),
+    /// A calendar style, and whether it's the Julian calendar's Old Style
+    Style(bool),
+    /// A year in `ParserInfo::offset_calendar`
+    YearOffset,
+    /// A month name of several words, numbered from 1
+    Month(usize),
+}
+
+/// The case-folded words of a marker, and what it marks. Words are
+/// separated by a space where the marker has only whitespace between them,
+/// and by a dot where it has punctuation, so "av. J.-C." is "av.j.c".
+type Phrase = (Cow<'static, str>, Marker);
+
+/// Words sorted by their case-folded form, and markers with the most words
+/// first
+pub(crate) struct Vocabulary {
+    entries: Cow<'static, [Entry]>,
+    markers: Cow<'static, [Phrase]>,
+}
+
+impl Vocabulary {
+    /// Compile the word lists of `info`
+    pub(crate) fn compile(info: &ParserInfo) -> Vocabulary {
+        let mut words = BTreeMap::new();
+
+        // Months of several words, like "Adar II", are matched as markers
+        let mut months = HashMap::new();
+        let mut month_phrases = Vec::new();
+        for (name, &idx) in &info.months {
+            if marker_words(&fold_phrase(name)).count() > 1 {
+                month_phrases.push((name, Marker::Month(idx + 1)));
+            } else {
+                months.insert(name.clone(), idx);
+            }
+        }
+
+        add(&mut words, &info.jump, |c, _| c.jump = true);
+        add(&mut words, &info.weekday, |c, i| c.weekday = Some(i));
+        add(&mut words, &months, |c, i| c.month = Some(i + 1));
+        add(&mut words, &info.hms, |c, i| c.hms = Some(i));
+        add(&mut words, &info.ampm, |c, i| c.ampm = Some(i == 1));
+        add(&mut words, &info.pertain, |c, _| c.pertain = true);
+        add(&mut words, &info.tzoffset, |c, i| c.tzoffset = Some(i));
+        add(&mut words, &info.julian_day, |c, i| {
+            c.julian_day = JulianDayKind::from_index(i)
+        });
+        add(&mut words, &info.utczone, |c, _| {
+            c.utczone = true;
+            c.tzoffset = Some(0);
+        });
+
+        for (idx, era) in info.regnal_eras.iter().enumerate() {
+            for name in &era.names {
+                let name = fold(name).collect::<String>();
+                for first_year in info.first_year.keys() {
+                    let word = name.chars().chain(fold(first_year)).collect();
+                    words.entry(word).or_insert(NONE).regnal_era = Some((idx, true));
+                }
+                words.entry(name).or_insert(NONE).regnal_era = Some((idx, false));
+            }
+        }
+
+        let mut markers = Vec::new();
+        let eras = info.era.iter().map(|(phrase, &idx)| (phrase, Marker::Era(idx == 0)));
+        let styles = info
+            .calendar_style
+            .iter()
+            .map(|(phrase, &idx)| (phrase, Marker::Style(idx == 0)));
+        let offset_years = info
+            .offset_calendar
+            .iter()
+            .flat_map(|calendar| calendar.markers.keys())
+            .map(|phrase| (phrase, Marker::YearOffset));
+        for (phrase, marker) in eras.chain(styles).chain(offset_years).chain(month_phrases) {
+            let folded = fold_phrase(phrase);
+            if folded.is_empty() {
+                continue;
+            }
+            let first = marker_words(&folded).next().unwrap_or_default().to_owned();
+            words.entry(first).or_insert(NONE).marker = true;
+            markers.push((Cow::Owned(folded), marker));
+        }
+        markers.sort_by_key(|phrase: &Phrase| (Reverse(marker_words(&phrase.0).count()), phrase.clone()));
+        markers.dedup();
+
+        let entries = words
+            .into_iter()
+            .map(|(word, categories)| (Cow::Owned(word), categories))
+            .collect::<Vec<_>>();
+        Vocabulary {
+            entries: Cow::Owned(entries),
+            markers: Cow::Owned(markers),
+        }
+    }
+
+    /// The words of `ParserInfo::default()`, without building it
+    pub(crate) fn english() -> Vocabulary {
+        Vocabulary {
+            entries: Cow::Borrowed(ENGLISH),
+            markers: Cow::Borrowed(ENGLISH_MARKERS),
+        }
+    }
+
+    /// Every role `token` has, ignoring case
+    pub(crate) fn lookup(&self, token: &str) -> &Categories {
+        self.entries
+            .binary_search_by(|entry| entry.0.chars().cmp(fold(token)))
+            .map(|idx| &self.entries[idx].1)
+            .unwrap_or(&NONE)
+    }
+
+    /// If a marker starts at `tokens[idx]`, the index of its last word and
+    /// what it marks. Any whitespace and punctuation may separate words the
+    /// marker separates with punctuation, so "B.C." matches "B. C." but not
+    /// "B C".
+    pub(crate) fn marker(&self, tokens: &[Cow<str>], idx: usize) -> Option<(usize, Marker)> {
+        if !self.lookup(&tokens[idx]).marker {
+            return None;
+        }
+
+        self.markers.iter().find_map(|&(ref phrase, marker)| {
+            let mut words = marker_words(phrase);
+            let mut seps = phrase.chars().filter(|&c| c == ' ' || c == '.');
+            let mut tok = idx;
+            if !fold(&tokens[tok]).eq(words.next()?.chars()) {
+                return None;
+            }
+
+            for word in words {
+                let punctuation = seps.next()? == '.';
+                let start = tok + 1;
+                tok = start;
+                while tokens.get(tok)?.chars().all(|c| !c.is_alphanumeric()) {
+                    tok += 1;
+                }
+
+                let gap = &tokens[start..tok];
+                let fits = if punctuation {
+                    gap.iter().any(|token| !token.trim().is_empty())
+                } else {
+                    !gap.is_empty() && gap.iter().all(|token| token.trim().is_empty())
+                };
+                if !fits || !fold(&tokens[tok]).eq(word.chars()) {
+                    return None;
+                }
+            }
+            Some((tok, marker))
+        })
+    }
+}
+
+/// Case-fold the words of a marker, separating them as `Phrase` describes
+fn fold_phrase(phrase: &str) -> String {
+    let mut folded = String::new();
+    let mut gap = None;
+    for token in Tokenizer::new(phrase) {
+        let token = fold(&token.value()).collect::<String>();
+        if !token.chars().any(char::is_alphanumeric) {
+            let sep = if token.trim().is_empty() { ' ' } else { '.' };
+            gap = Some(if gap == Some('.') { '.' } else { sep });
+            continue;
+        }
+        if !folded.is_empty() {
+            folded.push(gap.unwrap_or(' '));
+        }
+        folded.push_str(&token);
+        gap = None;
+    }
+    folded
+}
+
+fn marker_words(phrase: &str) -> impl Iterator<Item = &str> {
+    phrase.split([' ', '.'])
+}
+
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Vocabulary::english()
+    }
+}
+
+fn add<F>(words: &mut BTreeMap<String, Categories>, list: &HashMap<String, usize>, set: F)
+where
+    F: Fn(&mut Categories, usize),
+{
+    for (word, &idx) in list {
+        set(words.entry(fold(word).collect()).or_insert(NONE), idx);
+    }
+}
+
+/// Lowercase `word` one character at a time, treating the final form of
+/// sigma like any other so that "ΜΆΙΟΣ" and "Μάιος" are the same word
+fn fold<'a>(word: &'a str) -> impl Iterator<Item = char> + 'a {
+    word.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'ς' { 'σ' } else { c })
+}
+
+macro_rules! words {
+    ($($word:expr => { $($field:ident: $value:expr),* }),* $(,)*) => {
+        &[$((Cow::Borrowed($word), Categories { $($field: $value,)* ..NONE })),*]
+    };
+}
+
+/// The words of `ParserInfo::default()`, in sorted order
+static ENGLISH: &[Entry] = words![
+    " " => { jump: true },
+    "'" => { jump: true },
+    "," => { jump: true },
+    "-" => { jump: true },
+    "." => { jump: true },
+    "/" => { jump: true },
+    ";" => { jump: true },
+    "a" => { ampm: Some(false), marker: true },
+    "ad" => { marker: true },
+    "am" => { ampm: Some(false) },
+    "and" => { jump: true },
+    "apr" => { month: Some(4) },
+    "april" => { month: Some(4) },
+    "at" => { jump: true },
+    "aug" => { month: Some(8) },
+    "august" => { month: Some(8) },
+    "b" => { marker: true },
+    "bc" => { marker: true },
+    "bce" => { marker: true },
+    "c" => { marker: true },
+    "ce" => { marker: true },
+    "dec" => { month: Some(12) },
+    "december" => { month: Some(12) },
+    "feb" => { month: Some(2) },
+    "february" => { month: Some(2) },
+    "fri" => { weekday: Some(4) },
+    "friday" => { weekday: Some(4) },
+    "gmt" => { utczone: true, tzoffset: Some(0) },
+    "h" => { hms: Some(0) },
+    "hour" => { hms: Some(0) },
+    "hours" => { hms: Some(0) },
+    "jan" => { month: Some(1) },
+    "january" => { month: Some(1) },
+    "jd" => { julian_day: Some(JulianDayKind::Jd) },
+    "jul" => { month: Some(7) },
+    "july" => { month: Some(7) },
+    "jun" => { month: Some(6) },
+    "june" => { month: Some(6) },
+    "m" => { jump: true, hms: Some(1) },
+    "mar" => { month: Some(3) },
+    "march" => { month: Some(3) },
+    "may" => { month: Some(5) },
+    "minute" => { hms: Some(1) },
+    "minutes" => { hms: Some(1) },
+    "mjd" => { julian_day: Some(JulianDayKind::Mjd) },
+    "mon" => { weekday: Some(0) },
+    "monday" => { weekday: Some(0) },
+    "n" => { marker: true },
+    "nd" => { jump: true },
+    "new" => { marker: true },
+    "nov" => { month: Some(11) },
+    "november" => { month: Some(11) },
+    "o" => { marker: true },
+    "oct" => { month: Some(10) },
+    "october" => { month: Some(10) },
+    "of" => { jump: true, pertain: true },
+    "old" => { marker: true },
+    "on" => { jump: true },
+    "p" => { ampm: Some(true) },
+    "pm" => { ampm: Some(true) },
+    "rd" => { jump: true },
+    "rjd" => { julian_day: Some(JulianDayKind::Rjd) },
+    "s" => { hms: Some(2) },
+    "sat" => { weekday: Some(5) },
+    "saturday" => { weekday: Some(5) },
+    "second" => { hms: Some(2) },
+    "seconds" => { hms: Some(2) },
+    "sep" => { month: Some(9) },
+    "sept" => { month: Some(9) },
+    "september" => { month: Some(9) },
+    "st" => { jump: true },
+    "sun" => { weekday: Some(6) },
+    "sunday" => { weekday: Some(6) },
+    "t" => { jump: true },
+    "th" => { jump: true },
+    "thu" => { weekday: Some(3) },
+    "thurs" => { weekday: Some(3) },
+    "thursday" => { weekday: Some(3) },
+    "tue" => { weekday: Some(1) },
+    "tues" => { weekday: Some(1) },
+    "tuesday" => { weekday: Some(1) },
+    "utc" => { utczone: true, tzoffset: Some(0) },
+    "wed" => { weekday: Some(2) },
+    "wednesday" => { weekday: Some(2) },
+    "z" => { utczone: true, tzoffset: Some(0) },
+];
+
+/// The eras and calendar styles of `ParserInfo::default()`, in the order
+/// `Vocabulary::marker` tries them
+static ENGLISH_MARKERS: &[Phrase] = &[
+    (Cow::Borrowed("b.c.e"), Marker::Era(true)),
+    (Cow::Borrowed("a.d"), Marker::Era(false)),
+    (Cow::Borrowed("b.c"), Marker::Era(true)),
+    (Cow::Borrowed("c.e"), Marker::Era(false)),
+    (Cow::Borrowed("n.s"), Marker::Style(false)),
+    (Cow::Borrowed("new style"), Marker::Style(false)),
+    (Cow::Borrowed("o.s"), Marker::Style(true)),
+    (Cow::Borrowed("old style"), Marker::Style(true)),
+    (Cow::Borrowed("ad"), Marker::Era(false)),
+    (Cow::Borrowed("bc"), Marker::Era(true)),
+    (Cow::Borrowed("bce"), Marker::Era(true)),
+    (Cow::Borrowed("ce"), Marker::Era(false)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::Marker::{Era, Month, Style};
+    use super::Vocabulary;
+    use calendar::JulianDayKind;
+    use std::borrow::Cow;
+    use parse_info;
+    use tokenize::Tokenizer;
+    use ParserInfo;
+
+    #[test]
+    fn english_matches_default_info() {
+        let compiled = Vocabulary::compile(&ParserInfo::default());
+        assert_eq!(compiled.entries, Vocabulary::english().entries);
+        assert_eq!(compiled.markers, Vocabulary::english().markers);
+    }
+
+    #[test]
+    fn lookup_ignores_case() {
+        let vocab = Vocabulary::english();
+        assert_eq!(vocab.lookup("SEPT").month, Some(9));
+        assert_eq!(vocab.lookup("Thursday").weekday, Some(3));
+        assert_eq!(vocab.lookup("Z").tzoffset, Some(0));
+        assert_eq!(vocab.lookup("mJd").julian_day, Some(JulianDayKind::Mjd));
+        assert_eq!(vocab.lookup("Septembre").month, None);
+        assert_eq!(vocab.lookup("").month, None);
+    }
+
+    #[test]
+    fn lookup_finds_every_category() {
+        let vocab = Vocabulary::english();
+        let m = vocab.lookup("M");
+        assert!(m.jump);
+        assert_eq!(m.hms, Some(1));
+
+        let of = vocab.lookup("of");
+        assert!(of.jump && of.pertain);
+    }
+
+    #[test]
+    fn compiles_custom_words() {
+        let info = ParserInfo {
+            months: parse_info(vec![vec!["Μάρ", "Μάρτιος"], vec!["Μάι", "Μάιος"]]),
+            ..ParserInfo::default()
+        };
+        let vocab = Vocabulary::compile(&info);
+        assert_eq!(vocab.lookup("ΜΆΙΟΣ").month, Some(2));
+        assert_eq!(vocab.lookup("μάρτιος").month, Some(1));
+        assert_eq!(vocab.lookup("May").month, None);
+        assert!(vocab.lookup("of").pertain);
+    }
+
+    #[test]
+    fn matches_markers_across_tokens() {
+        let tokens = |timestr: &'static str| -> Vec<Cow<str>> {
+            Tokenizer::new(timestr).map(|t| t.value()).collect()
+        };
+        let vocab = Vocabulary::english();
+        assert_eq!(vocab.marker(&tokens("B.C.E. 44"), 0), Some((4, Era(true))));
+        assert_eq!(vocab.marker(&tokens("a.d."), 0), Some((2, Era(false))));
+        assert_eq!(vocab.marker(&tokens("AD"), 0), Some((0, Era(false))));
+        assert_eq!(vocab.marker(&tokens("a.m."), 0), None);
+        assert_eq!(vocab.marker(&tokens("B."), 0), None);
+        assert_eq!(vocab.marker(&tokens("B. C."), 0), Some((3, Era(true))));
+        assert_eq!(vocab.marker(&tokens("b c"), 0), None);
+        assert_eq!(vocab.marker(&tokens("O.S."), 0), Some((2, Style(true))));
+        assert_eq!(vocab.marker(&tokens("New Style"), 0), Some((2, Style(false))));
+        assert_eq!(vocab.marker(&tokens("old"), 0), None);
+
+        let info = ParserInfo {
+            era: parse_info(vec![vec!["до н. э."], vec!["н. э."]]),
+            ..ParserInfo::default()
+        };
+        let vocab = Vocabulary::compile(&info);
+        assert_eq!(vocab.marker(&tokens("до н. э."), 0), Some((5, Era(true))));
+        assert_eq!(vocab.marker(&tokens("до н. э."), 2), Some((5, Era(false))));
+        assert_eq!(vocab.marker(&tokens("BC"), 0), None);
+        assert_eq!(vocab.marker(&tokens("до н э"), 0), None);
+        assert_eq!(vocab.marker(&tokens("до. н. э."), 0), None);
+
+        let info = ParserInfo {
+            months: parse_info(vec![vec!["Adar", "Adar I"], vec!["Adar II"], vec!["Sha'ban"]]),
+            ..ParserInfo::default()
+        };
+        let vocab = Vocabulary::compile(&info);
+        assert_eq!(vocab.lookup("adar").month, Some(1));
+        assert_eq!(vocab.marker(&tokens("Adar II"), 0), Some((2, Month(2))));
+        assert_eq!(vocab.marker(&tokens("Adar I"), 0), Some((2, Month(1))));
+        assert_eq!(vocab.marker(&tokens("Sha'ban"), 0), Some((2, Month(3))));
+        assert_eq!(vocab.marker(&tokens("Adar"), 0), None);
+    }
+}